@@ -4,13 +4,27 @@ extern crate alloc;
 
 use alloc::{collections::BTreeMap, string::String};
 
-use contract::contract_api::{runtime, storage, system};
-use types::{Key, URef};
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key, URef};
 
 const KEY_ADMIN: &str = "admin";
 const NAME_SWAP_HASH: &str = "swap_hash";
 const NAME_SWAP_LOGIC_EXT: &str = "swap_logic_ext";
 const KEY_CONTRACT_PURSE: &str = "swap_contract_purse";
+const KEY_CONTRACT_HASH: &str = "swap_contract_hash";
+const KEY_VER1_PUBKEY_HASH_PREFIX: &str = "ver1_pubkey_hash_prefix";
+const KEY_VER1_SCRIPT_HASH_PREFIX: &str = "ver1_script_hash_prefix";
+const KEY_VER1_CHECKSUM_XOR: &str = "ver1_checksum_xor";
+const KEY_CLAIM_START: &str = "claim_start";
+const KEY_CLAIM_END: &str = "claim_end";
+
+// Hdac ver1 mainnet, used when the network parameters are not given
+const DEFAULT_VER1_PUBKEY_HASH_PREFIX: u8 = 0x28;
+const DEFAULT_VER1_SCRIPT_HASH_PREFIX: u8 = 0x08;
+const DEFAULT_VER1_CHECKSUM_XOR: u32 = 0x4844_4143;
 
 #[no_mangle]
 pub extern "C" fn swap_logic_ext() {
@@ -30,9 +44,65 @@ pub extern "C" fn call() {
     let contract_purse = system::create_purse();
     swapper_urefs.insert(String::from(KEY_CONTRACT_PURSE), contract_purse.into());
 
+    // Placeholder of the contract hash. It is known only after the contract is stored,
+    // and the contract uses it to bind ver1 signatures to this swap.
+    let contract_hash_uref: URef = storage::new_uref(Key::Hash([0u8; 32]));
+    swapper_urefs.insert(String::from(KEY_CONTRACT_HASH), contract_hash_uref.into());
+
+    // Ver1 network parameters.
+    // Optional args: pubkey hash prefix (u8), script hash prefix (u8), checksum XOR seed (u32)
+    let ver1_pubkey_hash_prefix: u8 = match runtime::get_arg(0) {
+        Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => DEFAULT_VER1_PUBKEY_HASH_PREFIX,
+    };
+    let ver1_script_hash_prefix: u8 = match runtime::get_arg(1) {
+        Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => DEFAULT_VER1_SCRIPT_HASH_PREFIX,
+    };
+    let ver1_checksum_xor: u32 = match runtime::get_arg(2) {
+        Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => DEFAULT_VER1_CHECKSUM_XOR,
+    };
+    swapper_urefs.insert(
+        String::from(KEY_VER1_PUBKEY_HASH_PREFIX),
+        storage::new_uref(ver1_pubkey_hash_prefix).into(),
+    );
+    swapper_urefs.insert(
+        String::from(KEY_VER1_SCRIPT_HASH_PREFIX),
+        storage::new_uref(ver1_script_hash_prefix).into(),
+    );
+    swapper_urefs.insert(
+        String::from(KEY_VER1_CHECKSUM_XOR),
+        storage::new_uref(ver1_checksum_xor).into(),
+    );
+
+    // Claim window in block time (milliseconds). Zero leaves that side open.
+    // Optional args: claim start (u64), claim end (u64)
+    let claim_start: u64 = match runtime::get_arg(3) {
+        Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => 0,
+    };
+    let claim_end: u64 = match runtime::get_arg(4) {
+        Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => 0,
+    };
+    if claim_end != 0 && claim_end <= claim_start {
+        runtime::revert(ApiError::InvalidArgument);
+    }
+    swapper_urefs.insert(
+        String::from(KEY_CLAIM_START),
+        storage::new_uref(claim_start).into(),
+    );
+    swapper_urefs.insert(
+        String::from(KEY_CLAIM_END),
+        storage::new_uref(claim_end).into(),
+    );
+
     // Swap function storage
     let swap_function_pointer = storage::store_function_at_hash(NAME_SWAP_LOGIC_EXT, swapper_urefs);
+    let swap_hash: Key = swap_function_pointer.into();
+    storage::write(contract_hash_uref, swap_hash);
 
     swap_proxy::deploy_swap_proxy();
-    runtime::put_key(NAME_SWAP_HASH, swap_function_pointer.into());
+    runtime::put_key(NAME_SWAP_HASH, swap_hash);
 }
@@ -1,24 +1,206 @@
 pub(crate) mod methods {
     pub const METHOD_INSERT_KYC_ALLOWANCE_CAP: &str = "insert_kyc_allowance_cap";
     pub const METHOD_INSERT_SNAPSHOT_RECORD: &str = "insert_snapshot_record";
+    pub const METHOD_INSERT_SNAPSHOT_RECORDS: &str = "insert_snapshot_records";
+    pub const METHOD_AMEND_SNAPSHOT_RECORD: &str = "amend_snapshot_record";
     pub const METHOD_GET_CONTRACT_PURSE: &str = "get_contract_purse";
     pub const METHOD_INSERT_KYC_DATA: &str = "insert_kyc_data";
     pub const METHOD_UPDATE_KYC_LEVEL: &str = "update_kyc_level";
     pub const METHOD_GET_TOKEN: &str = "get_token";
+    pub const METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE: &str = "get_token_with_signed_message";
+    pub const METHOD_INSERT_SNAPSHOT_MERKLE_ROOT: &str = "insert_snapshot_merkle_root";
+    pub const METHOD_GET_TOKEN_WITH_MERKLE_PROOF: &str = "get_token_with_merkle_proof";
+    pub const METHOD_GET_TOKEN_WITH_MULTISIG: &str = "get_token_with_multisig";
+    pub const METHOD_GET_SNAPSHOT_RECORD: &str = "get_snapshot_record";
+    pub const METHOD_GET_KYC_RECORD: &str = "get_kyc_record";
+    pub const METHOD_GET_ALLOWANCE_CAP: &str = "get_allowance_cap";
+    pub const METHOD_GET_ADMIN: &str = "get_admin";
+    pub const METHOD_PROPOSE_ADMIN: &str = "propose_admin";
+    pub const METHOD_ACCEPT_ADMIN: &str = "accept_admin";
+    pub const METHOD_GRANT_ROLE: &str = "grant_role";
+    pub const METHOD_REVOKE_ROLE: &str = "revoke_role";
+    pub const METHOD_SET_MULTISIG_CONFIG: &str = "set_multisig_config";
+    pub const METHOD_PROPOSE_ACTION: &str = "propose_action";
+    pub const METHOD_APPROVE_PROPOSAL: &str = "approve_proposal";
+    pub const METHOD_EXECUTE_PROPOSAL: &str = "execute_proposal";
+    pub const METHOD_CANCEL_PROPOSAL: &str = "cancel_proposal";
+    pub const METHOD_GET_PROPOSAL: &str = "get_proposal";
+    pub const METHOD_GET_PENDING_PROPOSALS: &str = "get_pending_proposals";
+    pub const METHOD_PAUSE_SWAP: &str = "pause_swap";
+    pub const METHOD_UNPAUSE_SWAP: &str = "unpause_swap";
+    pub const METHOD_SET_CLAIM_WINDOW: &str = "set_claim_window";
+    pub const METHOD_SWEEP_UNCLAIMED: &str = "sweep_unclaimed";
+    pub const METHOD_GET_RESERVE_STATUS: &str = "get_reserve_status";
+    pub const METHOD_GET_STATS: &str = "get_stats";
+    pub const METHOD_GET_RECEIPT: &str = "get_receipt";
+    pub const METHOD_GET_RECEIPTS_BY_ACCOUNT: &str = "get_receipts_by_account";
+    pub const METHOD_GET_EVENTS: &str = "get_events";
+    pub const METHOD_SET_KYC_TIER: &str = "set_kyc_tier";
+    pub const METHOD_GET_KYC_TIER: &str = "get_kyc_tier";
+    pub const METHOD_RENEW_KYC: &str = "renew_kyc";
+    pub const METHOD_GET_EXPIRING_KYC_RECORDS: &str = "get_expiring_kyc_records";
+    pub const METHOD_REVOKE_KYC: &str = "revoke_kyc";
+    pub const METHOD_FREEZE_ACCOUNT: &str = "freeze_account";
+    pub const METHOD_UNFREEZE_ACCOUNT: &str = "unfreeze_account";
+    pub const METHOD_ADD_TO_DENYLIST: &str = "add_to_denylist";
+    pub const METHOD_REMOVE_FROM_DENYLIST: &str = "remove_from_denylist";
+    pub const METHOD_IS_DENYLISTED: &str = "is_denylisted";
+    pub const METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT: &str = "replace_snapshot_merkle_root";
+    pub const METHOD_CLAIM_PENDING: &str = "claim_pending";
 }
 
 pub(crate) mod keys {
     pub const KEY_PREV_BALANCE_KEY: &str = "prev_balance";
     pub const KEY_IS_SWAPPED: &str = "is_swapped";
+    pub const KEY_IS_EXPIRED: &str = "is_expired";
+    pub const KEY_PENDING_AMOUNT: &str = "pending_amount";
+    pub const KEY_PENDING_CLAIMS_PREFIX: &str = "pending_claims_";
+    pub const KEY_DENYLIST_PREFIX: &str = "denylist_";
 
     pub const KEY_KYC_LEVEL: &str = "kyc_level";
     pub const KEY_SWAPPED_AMOUNT: &str = "swapped_amount";
+    pub const KEY_PERIOD_START: &str = "period_start";
+    pub const KEY_PERIOD_SWAPPED_AMOUNT: &str = "period_swapped_amount";
+    pub const KEY_EXPIRES_AT: &str = "expires_at";
+    pub const KEY_KYC_STATUS: &str = "status";
+    pub const KEY_KYC_MAINNET_ADDRESS: &str = "mainnet_address";
+
+    pub const KEY_KYC_ACCOUNT_COUNT: &str = "kyc_account_count";
+    pub const KEY_KYC_ACCOUNT_PREFIX: &str = "kyc_account_";
+    pub const KEY_KYC_ACCOUNT_INDEX_PREFIX: &str = "kyc_index_";
+
+    pub const KEY_KYC_TIER_PREFIX: &str = "kyc_tier_";
+    pub const KEY_TIER_KYC_LEVEL: &str = "kyc_level";
+    pub const KEY_TIER_CAP: &str = "cap";
+    pub const KEY_TIER_PERIOD_LIMIT: &str = "period_limit";
+    pub const KEY_TIER_PERIOD: &str = "period";
+    pub const KEY_TIER_ENABLED: &str = "enabled";
 
     pub const KEY_KYC_BORDER_ALLOWANCE_CAP: &str = "kyc_border_allowance_cap";
 
     pub const KEY_CONTRACT_PURSE: &str = "swap_contract_purse";
+    pub const KEY_CONTRACT_HASH: &str = "swap_contract_hash";
+
+    pub const KEY_VER1_PUBKEY_HASH_PREFIX: &str = "ver1_pubkey_hash_prefix";
+    pub const KEY_VER1_SCRIPT_HASH_PREFIX: &str = "ver1_script_hash_prefix";
+    pub const KEY_VER1_CHECKSUM_XOR: &str = "ver1_checksum_xor";
+
+    pub const KEY_SNAPSHOT_MERKLE_ROOT: &str = "snapshot_merkle_root";
+    pub const KEY_SNAPSHOT_MERKLE_TOTAL: &str = "snapshot_merkle_total";
+    pub const KEY_SNAPSHOT_MERKLE_CLAIMED_LEAVES: &str = "snapshot_merkle_claimed_leaves";
+    pub const KEY_SNAPSHOT_MERKLE_CLAIMED_AMOUNT: &str = "snapshot_merkle_claimed_amount";
+    pub const KEY_SNAPSHOT_MERKLE_EXPIRED: &str = "snapshot_merkle_expired";
+    pub const KEY_MERKLE_CLAIMED_BITMAP_PREFIX: &str = "merkle_claimed_bitmap_";
+    pub const KEY_MERKLE_CLAIMED_ADDRESS_PREFIX: &str = "merkle_claimed_address_";
+
+    pub const KEY_PAUSED: &str = "paused";
+    pub const KEY_CLAIM_START: &str = "claim_start";
+    pub const KEY_CLAIM_END: &str = "claim_end";
+    pub const KEY_SWEPT: &str = "swept";
+
+    pub const KEY_TOTAL_LIABILITIES: &str = "total_liabilities";
+    pub const KEY_TOTAL_CLAIMED: &str = "total_claimed";
+    pub const KEY_TOTAL_EXPIRED: &str = "total_expired";
+    pub const KEY_OUTSTANDING: &str = "outstanding";
+    pub const KEY_PURSE_BALANCE: &str = "purse_balance";
+
+    pub const KEY_SWAP_STATS: &str = "swap_stats";
+    pub const KEY_STATS_NUM_OF_SNAPSHOT_RECORDS: &str = "num_of_snapshot_records";
+    pub const KEY_STATS_NUM_OF_CLAIMED_RECORDS: &str = "num_of_claimed_records";
+    pub const KEY_STATS_NUM_OF_CLAIMING_ACCOUNTS: &str = "num_of_claiming_accounts";
+    pub const KEY_STATS_MERKLE_SNAPSHOT_AMOUNT: &str = "merkle_snapshot_amount";
+    pub const KEY_STATS_NUM_OF_CLAIMED_MERKLE_LEAVES: &str = "num_of_claimed_merkle_leaves";
+    pub const KEY_STATS_KYC_LEVEL_PREFIX: &str = "kyc_level_";
+    pub const KEY_STATS_REGISTRATIONS_SUFFIX: &str = "_registrations";
+    pub const KEY_STATS_SWAPPED_AMOUNT_SUFFIX: &str = "_swapped_amount";
+
+    pub const KEY_RECEIPT_COUNT: &str = "receipt_count";
+    pub const KEY_RECEIPT_PREFIX: &str = "receipt_";
+    pub const KEY_ACCOUNT_RECEIPTS_PREFIX: &str = "receipts_";
+    pub const KEY_RECEIPT_ID: &str = "receipt_id";
+    pub const KEY_RECEIPT_VER1_ADDRESS: &str = "ver1_address";
+    pub const KEY_RECEIPT_MAINNET_ADDRESS: &str = "mainnet_address";
+    pub const KEY_RECEIPT_AMOUNT: &str = "amount";
+    pub const KEY_RECEIPT_BLOCK_TIME: &str = "block_time";
+
+    pub const KEY_EVENT_COUNT: &str = "event_count";
+    pub const KEY_EVENT_PREFIX: &str = "event_";
+    pub const KEY_EVENT_SEQ: &str = "seq";
+    pub const KEY_EVENT_TYPE: &str = "event_type";
+    pub const KEY_EVENT_BLOCK_TIME: &str = "block_time";
+    pub const KEY_EVENT_VER1_ADDRESS: &str = "ver1_address";
+    pub const KEY_EVENT_MAINNET_ADDRESS: &str = "mainnet_address";
+    pub const KEY_EVENT_AMOUNT: &str = "amount";
+    pub const KEY_EVENT_KYC_LEVEL: &str = "kyc_level";
+    pub const KEY_EVENT_PREV_KYC_LEVEL: &str = "prev_kyc_level";
+    pub const KEY_EVENT_ALLOWANCE_CAP: &str = "allowance_cap";
+    pub const KEY_EVENT_PREV_ADMIN: &str = "prev_admin";
+    pub const KEY_EVENT_NEW_ADMIN: &str = "new_admin";
+    pub const KEY_EVENT_CAP: &str = "cap";
+    pub const KEY_EVENT_PERIOD_LIMIT: &str = "period_limit";
+    pub const KEY_EVENT_PERIOD: &str = "period";
+    pub const KEY_EVENT_ENABLED: &str = "enabled";
+    pub const KEY_EVENT_EXPIRES_AT: &str = "expires_at";
+    pub const KEY_EVENT_STATUS: &str = "status";
+    pub const KEY_EVENT_MERKLE_ROOT: &str = "merkle_root";
+
+    pub const KEY_MULTISIG_SIGNERS: &str = "multisig_signers";
+    pub const KEY_MULTISIG_THRESHOLD: &str = "multisig_threshold";
+    pub const KEY_PROPOSAL_COUNT: &str = "multisig_proposal_count";
+    pub const KEY_PENDING_PROPOSALS: &str = "multisig_pending_proposals";
+    pub const KEY_PROPOSAL_PREFIX: &str = "multisig_proposal_";
+    pub const KEY_PROPOSAL_APPROVAL_PREFIX: &str = "multisig_approval_";
+
+    pub const KEY_PROPOSAL_ACTION: &str = "action";
+    pub const KEY_PROPOSAL_PROPOSER: &str = "proposer";
+    pub const KEY_PROPOSAL_STATUS: &str = "status";
+    pub const KEY_PROPOSAL_NUM_OF_ARGS: &str = "num_of_args";
+    pub const KEY_PROPOSAL_ARG_PREFIX: &str = "arg_";
 }
 
 pub(crate) mod users {
     pub const KEY_ADMIN: &str = "admin";
+    pub const KEY_PENDING_ADMIN: &str = "pending_admin";
+    pub const KEY_ROLE_PREFIX: &str = "role_";
+}
+
+pub(crate) mod roles {
+    // insert_kyc_allowance_cap, set_kyc_tier, insert_kyc_data, update_kyc_level, renew_kyc,
+    // revoke_kyc, freeze_account, unfreeze_account
+    pub const ROLE_KYC_OPERATOR: &str = "kyc_operator";
+    // insert_snapshot_record, insert_snapshot_records, insert_snapshot_merkle_root,
+    // add_to_denylist
+    pub const ROLE_SNAPSHOT_OPERATOR: &str = "snapshot_operator";
+    // amend_snapshot_record, which moves funds of the contract purse
+    pub const ROLE_TREASURER: &str = "treasurer";
+}
+
+// Actions which need approvals of multisig signers, once the signers are configured
+pub(crate) mod actions {
+    // [allowance cap]
+    pub const ACTION_INSERT_KYC_ALLOWANCE_CAP: &str = "insert_kyc_allowance_cap";
+    // [ver1 address, new balance, refund recipient public key hex]
+    pub const ACTION_AMEND_SNAPSHOT_RECORD: &str = "amend_snapshot_record";
+    // [recipient public key hex, amount]
+    pub const ACTION_TRANSFER_PURSE_FUNDS: &str = "transfer_purse_funds";
+    // [threshold, signer public key hex, ...]
+    pub const ACTION_SET_MULTISIG_CONFIG: &str = "set_multisig_config";
+    // [treasury public key hex]
+    pub const ACTION_SWEEP_UNCLAIMED: &str = "sweep_unclaimed";
+    // [KYC level, cap, period limit, period, enabled]
+    pub const ACTION_SET_KYC_TIER: &str = "set_kyc_tier";
+    // [new admin public key hex]
+    pub const ACTION_PROPOSE_ADMIN: &str = "propose_admin";
+    // [role, account public key hex]
+    pub const ACTION_GRANT_ROLE: &str = "grant_role";
+    // [role, account public key hex]
+    pub const ACTION_REVOKE_ROLE: &str = "revoke_role";
+    // []
+    pub const ACTION_UNPAUSE_SWAP: &str = "unpause_swap";
+    // [claim start, claim end]
+    pub const ACTION_SET_CLAIM_WINDOW: &str = "set_claim_window";
+    // [ver1 address]
+    pub const ACTION_REMOVE_FROM_DENYLIST: &str = "remove_from_denylist";
+    // [merkle root, total amount, refund recipient public key hex]
+    pub const ACTION_REPLACE_SNAPSHOT_MERKLE_ROOT: &str = "replace_snapshot_merkle_root";
 }
@@ -9,7 +9,7 @@ use alloc::{string::String, vec::Vec};
 
 use crate::constants::methods;
 use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use types::{account::PublicKey, ApiError, CLValue, U512};
+use types::{account::PublicKey, ApiError, CLValue, Key, U512};
 
 #[no_mangle]
 pub extern "C" fn delegate() {
@@ -35,11 +35,267 @@ pub extern "C" fn delegate() {
 
             swap_control::insert_snapshot(ver1_address, prev_balance);
         }
+        methods::METHOD_AMEND_SNAPSHOT_RECORD => {
+            let ver1_address: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let new_balance: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let prev_balance = swap_control::amend_snapshot_record(ver1_address, new_balance);
+            let ret = CLValue::from_t(prev_balance).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_INSERT_SNAPSHOT_RECORDS => {
+            let ver1_addresses: Vec<String> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let prev_balances: Vec<U512> = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::insert_snapshot_records(ver1_addresses, prev_balances);
+        }
         methods::METHOD_GET_CONTRACT_PURSE => {
             let contract_purse = swap_control::get_contract_purse();
             let ret = CLValue::from_t(contract_purse).unwrap_or_revert();
             runtime::ret(ret)
         }
+        methods::METHOD_GET_SNAPSHOT_RECORD => {
+            let ver1_address: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let snapshot_record = swap_control::get_snapshot_record(ver1_address);
+            let ret = CLValue::from_t(snapshot_record).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_IS_DENYLISTED => {
+            let ver1_address: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let is_denylisted = swap_control::is_denylisted(ver1_address);
+            let ret = CLValue::from_t(is_denylisted).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_KYC_RECORD => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let kyc_record = swap_control::get_kyc_record(mainnet_address);
+            let ret = CLValue::from_t(kyc_record).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_KYC_TIER => {
+            let kyc_level: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let tier = swap_control::get_kyc_tier(kyc_level);
+            let ret = CLValue::from_t(tier).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_EXPIRING_KYC_RECORDS => {
+            let expires_before: u64 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let from: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let limit: U512 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let records = swap_control::get_expiring_kyc_records(expires_before, from, limit);
+            let ret = CLValue::from_t(records).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_RECEIPT => {
+            let ver1_address: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let receipt = swap_control::get_receipt(ver1_address);
+            let ret = CLValue::from_t(receipt).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_RECEIPTS_BY_ACCOUNT => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let receipts = swap_control::get_receipts_by_account(mainnet_address);
+            let ret = CLValue::from_t(receipts).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_EVENTS => {
+            let from: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let limit: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let events = swap_control::get_events(from, limit);
+            let ret = CLValue::from_t(events).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_ALLOWANCE_CAP => {
+            let allowance_cap = swap_control::get_allowance_cap();
+            let ret = CLValue::from_t(allowance_cap).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_ADMIN => {
+            let admin = swap_control::get_admin();
+            let ret = CLValue::from_t(admin).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_RESERVE_STATUS => {
+            let reserve_status = swap_control::get_reserve_status();
+            let ret = CLValue::from_t(reserve_status).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_STATS => {
+            let stats = swap_control::get_stats();
+            let ret = CLValue::from_t(stats).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_PROPOSE_ADMIN => {
+            let new_admin: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::propose_admin(new_admin);
+        }
+        methods::METHOD_ACCEPT_ADMIN => {
+            swap_control::accept_admin();
+        }
+        methods::METHOD_GRANT_ROLE => {
+            let role: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let account: PublicKey = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::grant_role(role, account);
+        }
+        methods::METHOD_REVOKE_ROLE => {
+            let role: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let account: PublicKey = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::revoke_role(role, account);
+        }
+        methods::METHOD_SET_MULTISIG_CONFIG => {
+            let signers: Vec<PublicKey> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let threshold: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::set_multisig_config(signers, threshold);
+        }
+        methods::METHOD_PROPOSE_ACTION => {
+            let action: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let args: Vec<String> = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let proposal_id = swap_control::propose_action(action, args);
+            let ret = CLValue::from_t(proposal_id).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_APPROVE_PROPOSAL => {
+            let proposal_id: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::approve_proposal(proposal_id);
+        }
+        methods::METHOD_EXECUTE_PROPOSAL => {
+            let proposal_id: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let required_funds = swap_control::execute_proposal(proposal_id);
+            let ret = CLValue::from_t(required_funds).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_CANCEL_PROPOSAL => {
+            let proposal_id: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::cancel_proposal(proposal_id);
+        }
+        methods::METHOD_GET_PROPOSAL => {
+            let proposal_id: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let proposal = swap_control::get_proposal(proposal_id);
+            let ret = CLValue::from_t(proposal).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_PENDING_PROPOSALS => {
+            let pending_proposals = swap_control::get_pending_proposals();
+            let ret = CLValue::from_t(pending_proposals).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_PAUSE_SWAP => {
+            swap_control::pause_swap();
+        }
+        methods::METHOD_UNPAUSE_SWAP => {
+            swap_control::unpause_swap();
+        }
+        methods::METHOD_SET_KYC_TIER => {
+            let kyc_level: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let cap: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let period_limit: U512 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let period: u64 = runtime::get_arg(4)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let enabled: bool = runtime::get_arg(5)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::set_kyc_tier(kyc_level, cap, period_limit, period, enabled);
+        }
+        methods::METHOD_SET_CLAIM_WINDOW => {
+            let claim_start: u64 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let claim_end: u64 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::set_claim_window(claim_start, claim_end);
+        }
+        methods::METHOD_SWEEP_UNCLAIMED => {
+            let target: Key = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let swept_amount = swap_control::sweep_unclaimed(target);
+            let ret = CLValue::from_t(swept_amount).unwrap_or_revert();
+            runtime::ret(ret)
+        }
         methods::METHOD_INSERT_KYC_DATA => {
             let new_mainnet_address: PublicKey = runtime::get_arg(1)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
@@ -47,8 +303,12 @@ pub extern "C" fn delegate() {
             let kyc_level: U512 = runtime::get_arg(2)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let expires_at: u64 = match runtime::get_arg(3) {
+                Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+                None => 0,
+            };
 
-            swap_control::insert_kyc_data(new_mainnet_address, kyc_level);
+            swap_control::insert_kyc_data(new_mainnet_address, kyc_level, expires_at);
         }
         methods::METHOD_UPDATE_KYC_LEVEL => {
             let new_mainnet_address: PublicKey = runtime::get_arg(1)
@@ -57,23 +317,148 @@ pub extern "C" fn delegate() {
             let kyc_level: U512 = runtime::get_arg(2)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let expires_at: Option<u64> =
+                runtime::get_arg(3).map(|val| val.unwrap_or_revert_with(ApiError::InvalidArgument));
 
-            swap_control::update_kyc_level(new_mainnet_address, kyc_level);
+            swap_control::update_kyc_level(new_mainnet_address, kyc_level, expires_at);
         }
-        methods::METHOD_GET_TOKEN => {
-            let ver1_pubkey_hex_arr: Vec<String> = runtime::get_arg(1)
+        methods::METHOD_RENEW_KYC => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let expires_at: u64 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::renew_kyc(mainnet_address, expires_at);
+        }
+        methods::METHOD_REVOKE_KYC => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::revoke_kyc(mainnet_address);
+        }
+        methods::METHOD_FREEZE_ACCOUNT => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::freeze_account(mainnet_address);
+        }
+        methods::METHOD_UNFREEZE_ACCOUNT => {
+            let mainnet_address: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::unfreeze_account(mainnet_address);
+        }
+        methods::METHOD_ADD_TO_DENYLIST => {
+            let ver1_addresses: Vec<String> = runtime::get_arg(1)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
-            let message_arr: Vec<String> = runtime::get_arg(2)
+
+            swap_control::add_to_denylist(ver1_addresses);
+        }
+        methods::METHOD_REMOVE_FROM_DENYLIST => {
+            let ver1_address: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::remove_from_denylist(ver1_address);
+        }
+        methods::METHOD_GET_TOKEN => {
+            let ver1_pubkey_hex_arr: Vec<String> = runtime::get_arg(1)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
-            let signature_hex_arr: Vec<String> = runtime::get_arg(3)
+            let signature_hex_arr: Vec<String> = runtime::get_arg(2)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let allow_partial: bool = match runtime::get_arg(3) {
+                Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+                None => false,
+            };
 
             swap_control::validate_sign_and_update_swapped_amount(
                 ver1_pubkey_hex_arr,
+                signature_hex_arr,
+                allow_partial,
+            );
+        }
+        methods::METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE => {
+            let message_arr: Vec<String> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let signature_base64_arr: Vec<String> = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::validate_signed_message_and_update_swapped_amount(
                 message_arr,
+                signature_base64_arr,
+            );
+        }
+        methods::METHOD_INSERT_SNAPSHOT_MERKLE_ROOT => {
+            let merkle_root: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let total_amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::insert_snapshot_merkle_root(merkle_root, total_amount);
+        }
+        methods::METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT => {
+            let merkle_root: String = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let total_amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            let prev_total = swap_control::replace_snapshot_merkle_root(merkle_root, total_amount);
+            let ret = CLValue::from_t(prev_total).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        methods::METHOD_GET_TOKEN_WITH_MERKLE_PROOF => {
+            let ver1_pubkey_hex_arr: Vec<String> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let signature_hex_arr: Vec<String> = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let leaf_index_arr: Vec<u64> = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let prev_balance_arr: Vec<U512> = runtime::get_arg(4)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let merkle_proof_arr: Vec<Vec<String>> = runtime::get_arg(5)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::validate_sign_and_claim_with_merkle_proof(
+                ver1_pubkey_hex_arr,
+                signature_hex_arr,
+                leaf_index_arr,
+                prev_balance_arr,
+                merkle_proof_arr,
+            );
+        }
+        methods::METHOD_CLAIM_PENDING => {
+            swap_control::claim_pending();
+        }
+        methods::METHOD_GET_TOKEN_WITH_MULTISIG => {
+            let redeem_script_hex_arr: Vec<String> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let signature_hex_arr: Vec<Vec<String>> = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+            swap_control::validate_multisig_and_update_swapped_amount(
+                redeem_script_hex_arr,
                 signature_hex_arr,
             );
         }
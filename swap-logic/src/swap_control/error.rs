@@ -43,6 +43,159 @@ pub enum Error {
 
     /// Ver1: Invalid signature
     InvalidVer1Signature,
+
+    /// Merkle: Root or proof hash decode failed
+    MerkleHashDecodeFail,
+
+    /// Merkle: Snapshot root is already registered
+    MerkleRootAlreadyRegistered,
+
+    /// Merkle: Snapshot root is not registered yet
+    MerkleRootNotRegistered,
+
+    /// Merkle: Proof does not lead to the registered root
+    InvalidMerkleProof,
+
+    /// Snapshot: Addresses and balances are empty or differ in length
+    InvalidNumOfSnapshotRecords,
+
+    /// Snapshot: Record of the address already exists
+    DuplicatedSnapshotRecord,
+
+    /// Snapshot: Ver1 address is malformed
+    MalformedVer1Address,
+
+    /// Snapshot: Record of the address does not exist
+    SnapshotRecordNotFound,
+
+    /// Ver1: Signature base64 decode failed
+    SignatureBase64DecodeFail,
+
+    /// Ver1: Public key recovery from the signature failed
+    PublicKeyRecoveryFail,
+
+    /// Ver1: Signed message is not the swap message of this claim
+    SwapMessageMismatch,
+
+    /// Ver1: Redeem script hex decode failed
+    RedeemScriptHexDecodeFail,
+
+    /// Ver1: Redeem script is not a standard multisig script
+    InvalidRedeemScript,
+
+    /// Snapshot: Checksum of the ver1 address does not match
+    InvalidVer1AddressChecksum,
+
+    /// Admin: Caller is not the proposed admin
+    NotPendingAdmin,
+
+    /// Role: Caller has neither the role nor admin
+    NoPermission,
+
+    /// Role: Unknown role name
+    InvalidRole,
+
+    /// Role: Account does not have the role
+    RoleNotGranted,
+
+    /// Multisig: The action should be proposed and approved by multisig signers
+    MultisigRequired,
+
+    /// Multisig: Signers are not configured yet
+    MultisigNotConfigured,
+
+    /// Multisig: Threshold is zero or larger than the number of signers, or signers are duplicated
+    InvalidMultisigConfig,
+
+    /// Multisig: Caller is not a multisig signer
+    NotMultisigSigner,
+
+    /// Multisig: Proposal does not exist
+    ProposalNotFound,
+
+    /// Multisig: Proposal is already executed or cancelled
+    ProposalNotPending,
+
+    /// Multisig: Caller already approved the proposal
+    AlreadyApprovedProposal,
+
+    /// Multisig: Approvals of the proposal are less than the threshold
+    NotEnoughApprovals,
+
+    /// Multisig: Unknown action
+    InvalidProposalAction,
+
+    /// Multisig: Arguments do not match the action
+    InvalidProposalArgs,
+
+    /// Swap: Claims are paused by admin
+    Paused,
+
+    /// Swap: Claim window is not open yet
+    ClaimNotYetOpen,
+
+    /// Swap: Claim window is already closed
+    ClaimClosed,
+
+    /// Swap: Claim end time is not later than the start time
+    InvalidClaimWindow,
+
+    /// Sweep: Claim window has no deadline, or it is not closed yet
+    SweepNotAllowed,
+
+    /// Sweep: Unclaimed funds are already swept
+    AlreadySwept,
+
+    /// Sweep: Target is neither an account nor a purse
+    InvalidSweepTarget,
+
+    /// Reserve: Payout would push the purse below the outstanding liabilities of others
+    InsufficientReserve,
+
+    /// Receipt: No receipt for the ver1 address
+    ReceiptNotFound,
+
+    /// KYC tier: Tier of the level is disabled
+    KYCTierDisabled,
+
+    /// KYC tier: Level is 0, or only one of the period limit and the period is given
+    InvalidKYCTier,
+
+    /// KYC tier: Claim exceeds the limit of the current period
+    ExceededPeriodLimit,
+
+    /// KYC: Approval of the account is expired
+    KYCExpired,
+
+    /// KYC: Expiry is not later than the current block time
+    InvalidKYCExpiry,
+
+    /// KYC: Account is frozen
+    AccountFrozen,
+
+    /// KYC: Account is not frozen
+    AccountNotFrozen,
+
+    /// KYC: Approval of the account is revoked
+    KYCRevoked,
+
+    /// Denylist: Ver1 address is denied to claim
+    DeniedVer1Address,
+
+    /// Denylist: Ver1 address is not in the denylist
+    NotDenylisted,
+
+    /// Denylist: No ver1 address is given
+    InvalidNumOfDenylistEntries,
+
+    /// Merkle: A leaf of the root is already claimed
+    MerkleLeafAlreadyClaimed,
+
+    /// Partial claim: The caller has no unpaid remainder
+    NoPendingClaims,
+
+    /// Multisig: Only the proposer can cancel the proposal
+    NotProposer,
 }
 
 impl From<Error> for ApiError {
@@ -0,0 +1,283 @@
+use super::{
+    swap_storage,
+    swap_storage::{KYCStatus, UnitKYCTierData},
+};
+use crate::constants::keys;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use contract::contract_api::runtime;
+use types::{account::PublicKey, U512};
+
+// Upper bound of events returned by one query, to keep the query within the gas limit
+const MAX_EVENTS_PER_QUERY: u64 = 100;
+
+const EVENT_SNAPSHOT_INSERTED: &str = "SnapshotInserted";
+const EVENT_KYC_REGISTERED: &str = "KycRegistered";
+const EVENT_KYC_LEVEL_CHANGED: &str = "KycLevelChanged";
+const EVENT_CAP_CHANGED: &str = "CapChanged";
+const EVENT_TOKENS_CLAIMED: &str = "TokensClaimed";
+const EVENT_ADMIN_CHANGED: &str = "AdminChanged";
+const EVENT_KYC_TIER_CHANGED: &str = "KycTierChanged";
+const EVENT_KYC_RENEWED: &str = "KycRenewed";
+const EVENT_KYC_STATUS_CHANGED: &str = "KycStatusChanged";
+const EVENT_DENYLIST_ADDED: &str = "DenylistAdded";
+const EVENT_DENYLIST_REMOVED: &str = "DenylistRemoved";
+const EVENT_SNAPSHOT_MERKLE_ROOT_INSERTED: &str = "SnapshotMerkleRootInserted";
+const EVENT_SNAPSHOT_MERKLE_ROOT_REPLACED: &str = "SnapshotMerkleRootReplaced";
+
+pub enum SwapEvent {
+    SnapshotInserted {
+        ver1_address: String,
+        amount: U512,
+    },
+    KycRegistered {
+        mainnet_address: PublicKey,
+        kyc_level: U512,
+    },
+    KycLevelChanged {
+        mainnet_address: PublicKey,
+        prev_kyc_level: U512,
+        kyc_level: U512,
+    },
+    CapChanged {
+        allowance_cap: U512,
+    },
+    TokensClaimed {
+        ver1_address: String,
+        mainnet_address: PublicKey,
+        amount: U512,
+    },
+    AdminChanged {
+        prev_admin: PublicKey,
+        new_admin: PublicKey,
+    },
+    KycTierChanged {
+        kyc_level: U512,
+        tier: UnitKYCTierData,
+    },
+    KycRenewed {
+        mainnet_address: PublicKey,
+        expires_at: u64,
+    },
+    KycStatusChanged {
+        mainnet_address: PublicKey,
+        status: KYCStatus,
+    },
+    DenylistAdded {
+        ver1_address: String,
+    },
+    DenylistRemoved {
+        ver1_address: String,
+    },
+    SnapshotMerkleRootInserted {
+        merkle_root: String,
+        amount: U512,
+    },
+    SnapshotMerkleRootReplaced {
+        merkle_root: String,
+        amount: U512,
+    },
+}
+
+impl SwapEvent {
+    fn organize(&self) -> BTreeMap<String, String> {
+        let mut res: BTreeMap<String, String> = BTreeMap::new();
+        let event_type = match self {
+            SwapEvent::SnapshotInserted {
+                ver1_address,
+                amount,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_VER1_ADDRESS.to_string(),
+                    ver1_address.clone(),
+                );
+                res.insert(keys::KEY_EVENT_AMOUNT.to_string(), amount.to_string());
+                EVENT_SNAPSHOT_INSERTED
+            }
+            SwapEvent::KycRegistered {
+                mainnet_address,
+                kyc_level,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_MAINNET_ADDRESS.to_string(),
+                    swap_storage::to_hex_string(*mainnet_address),
+                );
+                res.insert(keys::KEY_EVENT_KYC_LEVEL.to_string(), kyc_level.to_string());
+                EVENT_KYC_REGISTERED
+            }
+            SwapEvent::KycLevelChanged {
+                mainnet_address,
+                prev_kyc_level,
+                kyc_level,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_MAINNET_ADDRESS.to_string(),
+                    swap_storage::to_hex_string(*mainnet_address),
+                );
+                res.insert(
+                    keys::KEY_EVENT_PREV_KYC_LEVEL.to_string(),
+                    prev_kyc_level.to_string(),
+                );
+                res.insert(keys::KEY_EVENT_KYC_LEVEL.to_string(), kyc_level.to_string());
+                EVENT_KYC_LEVEL_CHANGED
+            }
+            SwapEvent::CapChanged { allowance_cap } => {
+                res.insert(
+                    keys::KEY_EVENT_ALLOWANCE_CAP.to_string(),
+                    allowance_cap.to_string(),
+                );
+                EVENT_CAP_CHANGED
+            }
+            SwapEvent::TokensClaimed {
+                ver1_address,
+                mainnet_address,
+                amount,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_VER1_ADDRESS.to_string(),
+                    ver1_address.clone(),
+                );
+                res.insert(
+                    keys::KEY_EVENT_MAINNET_ADDRESS.to_string(),
+                    swap_storage::to_hex_string(*mainnet_address),
+                );
+                res.insert(keys::KEY_EVENT_AMOUNT.to_string(), amount.to_string());
+                EVENT_TOKENS_CLAIMED
+            }
+            SwapEvent::AdminChanged {
+                prev_admin,
+                new_admin,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_PREV_ADMIN.to_string(),
+                    swap_storage::to_hex_string(*prev_admin),
+                );
+                res.insert(
+                    keys::KEY_EVENT_NEW_ADMIN.to_string(),
+                    swap_storage::to_hex_string(*new_admin),
+                );
+                EVENT_ADMIN_CHANGED
+            }
+            SwapEvent::KycTierChanged { kyc_level, tier } => {
+                res.insert(keys::KEY_EVENT_KYC_LEVEL.to_string(), kyc_level.to_string());
+                res.insert(
+                    keys::KEY_EVENT_CAP.to_string(),
+                    tier.cap.unwrap_or_default().to_string(),
+                );
+                res.insert(
+                    keys::KEY_EVENT_PERIOD_LIMIT.to_string(),
+                    tier.period_limit.to_string(),
+                );
+                res.insert(keys::KEY_EVENT_PERIOD.to_string(), tier.period.to_string());
+                res.insert(
+                    keys::KEY_EVENT_ENABLED.to_string(),
+                    tier.enabled.to_string(),
+                );
+                EVENT_KYC_TIER_CHANGED
+            }
+            SwapEvent::KycRenewed {
+                mainnet_address,
+                expires_at,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_MAINNET_ADDRESS.to_string(),
+                    swap_storage::to_hex_string(*mainnet_address),
+                );
+                res.insert(
+                    keys::KEY_EVENT_EXPIRES_AT.to_string(),
+                    expires_at.to_string(),
+                );
+                EVENT_KYC_RENEWED
+            }
+            SwapEvent::KycStatusChanged {
+                mainnet_address,
+                status,
+            } => {
+                res.insert(
+                    keys::KEY_EVENT_MAINNET_ADDRESS.to_string(),
+                    swap_storage::to_hex_string(*mainnet_address),
+                );
+                res.insert(
+                    keys::KEY_EVENT_STATUS.to_string(),
+                    status.as_str().to_string(),
+                );
+                EVENT_KYC_STATUS_CHANGED
+            }
+            SwapEvent::DenylistAdded { ver1_address } => {
+                res.insert(
+                    keys::KEY_EVENT_VER1_ADDRESS.to_string(),
+                    ver1_address.clone(),
+                );
+                EVENT_DENYLIST_ADDED
+            }
+            SwapEvent::DenylistRemoved { ver1_address } => {
+                res.insert(
+                    keys::KEY_EVENT_VER1_ADDRESS.to_string(),
+                    ver1_address.clone(),
+                );
+                EVENT_DENYLIST_REMOVED
+            }
+            SwapEvent::SnapshotMerkleRootInserted {
+                merkle_root,
+                amount,
+            } => {
+                res.insert(keys::KEY_EVENT_MERKLE_ROOT.to_string(), merkle_root.clone());
+                res.insert(keys::KEY_EVENT_AMOUNT.to_string(), amount.to_string());
+                EVENT_SNAPSHOT_MERKLE_ROOT_INSERTED
+            }
+            SwapEvent::SnapshotMerkleRootReplaced {
+                merkle_root,
+                amount,
+            } => {
+                res.insert(keys::KEY_EVENT_MERKLE_ROOT.to_string(), merkle_root.clone());
+                res.insert(keys::KEY_EVENT_AMOUNT.to_string(), amount.to_string());
+                EVENT_SNAPSHOT_MERKLE_ROOT_REPLACED
+            }
+        };
+        res.insert(keys::KEY_EVENT_TYPE.to_string(), event_type.to_string());
+
+        res
+    }
+}
+
+// Appends the event with the next sequence number. Sequence numbers start from 1.
+pub fn emit(event: SwapEvent) {
+    let seq = swap_storage::load_event_count() + U512::from(1);
+    let block_time: u64 = runtime::get_blocktime().into();
+
+    let mut record = event.organize();
+    record.insert(keys::KEY_EVENT_SEQ.to_string(), seq.to_string());
+    record.insert(
+        keys::KEY_EVENT_BLOCK_TIME.to_string(),
+        block_time.to_string(),
+    );
+
+    swap_storage::save_event(seq, record);
+    swap_storage::save_event_count(seq);
+}
+
+// Returns events from the sequence number in order, at most `limit` and MAX_EVENTS_PER_QUERY.
+// An indexer tails the log by querying again from the last sequence number + 1.
+pub fn get_events(from: U512, limit: U512) -> Vec<BTreeMap<String, String>> {
+    let event_count = swap_storage::load_event_count();
+    let limit = if limit > U512::from(MAX_EVENTS_PER_QUERY) {
+        U512::from(MAX_EVENTS_PER_QUERY)
+    } else {
+        limit
+    };
+
+    let mut res = Vec::new();
+    let mut seq = if from == U512::from(0) {
+        U512::from(1)
+    } else {
+        from
+    };
+    while seq <= event_count && U512::from(res.len()) < limit {
+        res.push(swap_storage::load_event(seq));
+        seq += U512::from(1);
+    }
+    res
+}
@@ -0,0 +1,197 @@
+use super::{
+    error::Error as SwapError,
+    events,
+    events::SwapEvent,
+    swap_storage,
+    swap_storage::{UnitKYCData, UnitKYCTierData},
+};
+use crate::constants::keys;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use contract::contract_api::runtime;
+use types::U512;
+
+// Levels which are not in the tier table keep the behavior before the table.
+// Level 1 is capped by kyc_border_allowance_cap, and level 2 has no limit.
+const LEGACY_CAPPED_LEVEL: u64 = 1;
+const LEGACY_UNLIMITED_LEVEL: u64 = 2;
+
+// Callers check the role and the multisig configuration
+pub fn apply_kyc_tier(kyc_level: U512, tier: UnitKYCTierData) {
+    if kyc_level == U512::from(0) || (tier.period_limit == U512::from(0)) != (tier.period == 0) {
+        runtime::revert(SwapError::InvalidKYCTier);
+    }
+
+    swap_storage::save_kyc_tier(kyc_level, &tier);
+    events::emit(SwapEvent::KycTierChanged { kyc_level, tier });
+}
+
+pub fn get_kyc_tier(kyc_level: U512) -> BTreeMap<String, String> {
+    let tier = match load_kyc_tier(kyc_level) {
+        Some(tier) => tier,
+        None => runtime::revert(SwapError::InvalidKYCLevelValue),
+    };
+
+    let mut res = tier.organize();
+    res.insert(keys::KEY_TIER_KYC_LEVEL.to_string(), kyc_level.to_string());
+    res
+}
+
+// Reverts unless the level is in the table, or is a legacy level, and enabled
+pub fn load_active_kyc_tier(kyc_level: U512) -> UnitKYCTierData {
+    let tier = match load_kyc_tier(kyc_level) {
+        Some(tier) => tier,
+        None => runtime::revert(SwapError::InvalidKYCLevelValue),
+    };
+    if !tier.enabled {
+        runtime::revert(SwapError::KYCTierDisabled);
+    }
+    tier
+}
+
+// Returns the error of a claim which goes over the cap or the limit of the current period.
+// A claim up to exactly the cap is allowed, as a partial claim pays up to the cap.
+pub fn claim_limit_error(
+    tier: &UnitKYCTierData,
+    curr_user_kyc_data: &UnitKYCData,
+    amount: U512,
+    now: u64,
+) -> Option<SwapError> {
+    if let Some(cap) = tier.cap {
+        if curr_user_kyc_data.swapped_amount + amount > cap {
+            return Some(SwapError::ExceededSwapRange);
+        }
+    }
+    if let Some(remaining) = remaining_period_allowance(tier, curr_user_kyc_data, now) {
+        if amount > remaining {
+            return Some(SwapError::ExceededPeriodLimit);
+        }
+    }
+    None
+}
+
+// The amount which can be paid right now, or None if the tier has no limit
+pub fn remaining_allowance(
+    tier: &UnitKYCTierData,
+    curr_user_kyc_data: &UnitKYCData,
+    now: u64,
+) -> Option<U512> {
+    let remaining_cap = tier
+        .cap
+        .map(|cap| cap.saturating_sub(curr_user_kyc_data.swapped_amount));
+    let remaining_period = remaining_period_allowance(tier, curr_user_kyc_data, now);
+
+    match (remaining_cap, remaining_period) {
+        (Some(cap), Some(period)) => Some(if cap < period { cap } else { period }),
+        (cap, None) => cap,
+        (None, period) => period,
+    }
+}
+
+pub fn count_period_usage(curr_user_kyc_data: &mut UnitKYCData, amount: U512, now: u64) {
+    let tier = match load_kyc_tier(curr_user_kyc_data.kyc_level) {
+        Some(tier) => tier,
+        None => return,
+    };
+    if tier.period == 0 {
+        return;
+    }
+
+    let period_start = period_window_start(now, tier.period);
+    if curr_user_kyc_data.period_start != period_start {
+        curr_user_kyc_data.period_start = period_start;
+        curr_user_kyc_data.period_swapped_amount = U512::from(0);
+    }
+    curr_user_kyc_data.period_swapped_amount += amount;
+}
+
+fn load_kyc_tier(kyc_level: U512) -> Option<UnitKYCTierData> {
+    if swap_storage::check_kyc_tier_existence(kyc_level) {
+        return Some(swap_storage::load_kyc_tier(kyc_level));
+    }
+
+    if kyc_level == U512::from(LEGACY_CAPPED_LEVEL) {
+        Some(UnitKYCTierData {
+            cap: Some(swap_storage::load_kyc_border_allowance_cap()),
+            period_limit: U512::from(0),
+            period: 0,
+            enabled: true,
+        })
+    } else if kyc_level == U512::from(LEGACY_UNLIMITED_LEVEL) {
+        Some(UnitKYCTierData {
+            cap: None,
+            period_limit: U512::from(0),
+            period: 0,
+            enabled: true,
+        })
+    } else {
+        None
+    }
+}
+
+fn remaining_period_allowance(
+    tier: &UnitKYCTierData,
+    curr_user_kyc_data: &UnitKYCData,
+    now: u64,
+) -> Option<U512> {
+    if tier.period == 0 {
+        return None;
+    }
+
+    if curr_user_kyc_data.period_start == period_window_start(now, tier.period) {
+        Some(
+            tier.period_limit
+                .saturating_sub(curr_user_kyc_data.period_swapped_amount),
+        )
+    } else {
+        Some(tier.period_limit)
+    }
+}
+
+// Periods are aligned to multiples of the period from the epoch,
+// so that every account of the tier shares the same boundaries.
+fn period_window_start(now: u64, period: u64) -> u64 {
+    now - now % period
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{period_window_start, remaining_allowance, UnitKYCData, UnitKYCTierData};
+    use crate::swap_control::swap_storage::KYCStatus;
+    use types::U512;
+
+    #[test]
+    pub fn test_should_limit_claim_by_cap_and_period() {
+        let tier = UnitKYCTierData {
+            cap: Some(U512::from(10_000)),
+            period_limit: U512::from(3_000),
+            period: 1_000,
+            enabled: true,
+        };
+        let mut kyc_data = UnitKYCData {
+            kyc_level: U512::from(3),
+            swapped_amount: U512::from(8_000),
+            period_start: 2_000,
+            period_swapped_amount: U512::from(1_000),
+            expires_at: 0,
+            status: KYCStatus::Active,
+        };
+
+        assert_eq!(period_window_start(2_999, 1_000), 2_000);
+        assert_eq!(period_window_start(3_000, 1_000), 3_000);
+
+        // Remaining of the current period
+        assert_eq!(
+            remaining_allowance(&tier, &kyc_data, 2_500),
+            Some(U512::from(2_000))
+        );
+        // A new period restores the period limit, but the cap remains
+        kyc_data.swapped_amount = U512::from(9_000);
+        assert_eq!(
+            remaining_allowance(&tier, &kyc_data, 3_500),
+            Some(U512::from(1_000))
+        );
+    }
+}
@@ -0,0 +1,81 @@
+extern crate hex;
+
+use super::error::Error as SwapError;
+use alloc::{string::String, vec::Vec};
+use contract::contract_api::runtime;
+use sha2::{Digest, Sha256};
+use types::U512;
+
+// Domain separation between leaves and inner nodes,
+// so that an inner node can never be presented as a leaf.
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Leaf = SHA256(0x00 || leaf index (u64, BE) || ver1 address || balance (U512, 64 bytes BE))
+pub fn leaf_hash(leaf_index: u64, ver1_address: &str, prev_balance: U512) -> [u8; 32] {
+    let mut balance_bytes: [u8; 64] = [0u8; 64];
+    prev_balance.to_big_endian(&mut balance_bytes);
+
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input([LEAF_PREFIX]);
+    sha256hasher.input(leaf_index.to_be_bytes());
+    sha256hasher.input(ver1_address.as_bytes());
+    sha256hasher.input(&balance_bytes[..]);
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(sha256hasher.result().as_slice());
+    res
+}
+
+/// Node = SHA256(0x01 || left || right)
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input([NODE_PREFIX]);
+    sha256hasher.input(left);
+    sha256hasher.input(right);
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(sha256hasher.result().as_slice());
+    res
+}
+
+/// Walks from the leaf to the root. The bits of the leaf index decide
+/// whether the sibling of each level sits on the left or on the right.
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], leaf_index: u64, proof: &[[u8; 32]]) -> bool {
+    // A u64 index addresses at most 64 levels
+    if proof.len() > 64 {
+        return false;
+    }
+    if proof.len() < 64 && leaf_index >> proof.len() != 0 {
+        return false;
+    }
+
+    let mut computed = leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        computed = if (leaf_index >> level) & 1 == 0 {
+            node_hash(&computed, sibling)
+        } else {
+            node_hash(sibling, &computed)
+        };
+    }
+
+    &computed == root
+}
+
+pub fn decode_hash(hash_hex: String) -> [u8; 32] {
+    let hash_bytes = match hex::decode(hash_hex) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::MerkleHashDecodeFail),
+    };
+    if hash_bytes.len() != 32 {
+        runtime::revert(SwapError::MerkleHashDecodeFail);
+    }
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(&hash_bytes);
+    res
+}
+
+pub fn decode_proof(proof_hex: Vec<String>) -> Vec<[u8; 32]> {
+    proof_hex.into_iter().map(decode_hash).collect()
+}
@@ -1,39 +1,401 @@
 mod error;
+mod events;
+mod kyc_tier;
+mod merkle;
+mod multisig;
+mod stats;
 mod swap_storage;
 mod ver1;
 
+use crate::constants::{keys, roles};
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
-use contract::contract_api::{runtime, system};
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
 use error::Error as SwapError;
-use num_traits::cast::AsPrimitive;
-use swap_storage::{UnitKYCData, UnitSnapshotData};
-use types::{account::PublicKey, AccessRights, TransferResult, URef, U512};
+use events::SwapEvent;
+use swap_storage::{KYCStatus, UnitKYCData, UnitKYCTierData, UnitReceiptData, UnitSnapshotData};
+use types::{account::PublicKey, AccessRights, Key, TransferResult, URef, U512};
+
+use ver1::{
+    derive_ver1_address, derive_ver1_p2sh_address, multisig_verification,
+    parse_multisig_redeem_script, recover_ver1_pubkey, signature_verification, swap_message,
+    swap_message_digest, validate_ver1_address, verify_ver1_address_checksum, Ver1NetworkParams,
+};
+
+pub use events::get_events;
+pub use kyc_tier::get_kyc_tier;
+pub use multisig::{
+    approve_proposal, cancel_proposal, execute_proposal, get_pending_proposals, get_proposal,
+    propose_action, set_multisig_config,
+};
+pub use stats::get_stats;
 
-use ver1::{derive_ver1_address, signature_verification};
+// Upper bound of KYC records scanned by one query, to keep the query within the gas limit
+const MAX_KYC_RECORDS_PER_QUERY: u64 = 100;
 
 // Admin features
 
 pub fn insert_kyc_allowance_cap(allowance_cap: U512) {
-    if runtime::get_caller() != swap_storage::load_admin() {
-        runtime::revert(SwapError::NotAdmin);
-    }
+    check_role(roles::ROLE_KYC_OPERATOR);
+    multisig::check_multisig_not_configured();
 
     swap_storage::save_kyc_border_allowance_cap(allowance_cap);
+    events::emit(SwapEvent::CapChanged { allowance_cap });
+}
+
+// Cap 0 means no cap. Period limit and period are both 0 for a tier without per-period limit.
+// Level 1 without a tier keeps using kyc_border_allowance_cap.
+pub fn set_kyc_tier(kyc_level: U512, cap: U512, period_limit: U512, period: u64, enabled: bool) {
+    check_role(roles::ROLE_KYC_OPERATOR);
+    multisig::check_multisig_not_configured();
+
+    kyc_tier::apply_kyc_tier(
+        kyc_level,
+        UnitKYCTierData::new(cap, period_limit, period, enabled),
+    );
 }
 
 pub fn insert_snapshot(ver1_address: String, prev_balance: U512) {
-    if runtime::get_caller() != swap_storage::load_admin() {
-        runtime::revert(SwapError::NotAdmin);
+    check_role(roles::ROLE_SNAPSHOT_OPERATOR);
+    check_not_swept();
+
+    check_ver1_address(&ver1_address, &swap_storage::load_ver1_network_params());
+
+    // Never overwrite, or a swapped record becomes claimable again.
+    // Use amend_snapshot_record to fix the balance of an unclaimed record.
+    if swap_storage::check_snapshot_data_existence(&ver1_address) {
+        runtime::revert(SwapError::DuplicatedSnapshotRecord);
     }
 
     let new_data = UnitSnapshotData {
         prev_balance,
         is_swapped: U512::from(0),
+        pending_amount: U512::from(0),
     };
-    swap_storage::save_snapshot_data(ver1_address, new_data);
+    swap_storage::save_snapshot_data(ver1_address.clone(), new_data);
+
+    swap_storage::save_total_liabilities(swap_storage::load_total_liabilities() + prev_balance);
+    stats::count_snapshot_records(1);
+    events::emit(SwapEvent::SnapshotInserted {
+        ver1_address,
+        amount: prev_balance,
+    });
+}
+
+pub fn insert_snapshot_records(ver1_addresses: Vec<String>, prev_balances: Vec<U512>) {
+    check_role(roles::ROLE_SNAPSHOT_OPERATOR);
+    check_not_swept();
+
+    if ver1_addresses.is_empty() || ver1_addresses.len() != prev_balances.len() {
+        runtime::revert(SwapError::InvalidNumOfSnapshotRecords);
+    }
+
+    let network_params = swap_storage::load_ver1_network_params();
+
+    // Any failure reverts the whole batch, including records written before it.
+    // Duplicates inside the batch are caught as the earlier one is already written.
+    let num_of_records = ver1_addresses.len();
+    let mut total_liabilities = swap_storage::load_total_liabilities();
+    for (ver1_address, prev_balance) in ver1_addresses.into_iter().zip(prev_balances) {
+        check_ver1_address(&ver1_address, &network_params);
+        if swap_storage::check_snapshot_data_existence(&ver1_address) {
+            runtime::revert(SwapError::DuplicatedSnapshotRecord);
+        }
+
+        let new_data = UnitSnapshotData {
+            prev_balance,
+            is_swapped: U512::from(0),
+            pending_amount: U512::from(0),
+        };
+        swap_storage::save_snapshot_data(ver1_address.clone(), new_data);
+        total_liabilities += prev_balance;
+        events::emit(SwapEvent::SnapshotInserted {
+            ver1_address,
+            amount: prev_balance,
+        });
+    }
+    swap_storage::save_total_liabilities(total_liabilities);
+    stats::count_snapshot_records(num_of_records);
+}
+
+// Returns the balance before amendment, so that the caller can fund the difference.
+// If the balance decreases, the difference is refunded to the admin from the contract purse.
+pub fn amend_snapshot_record(ver1_address: String, new_balance: U512) -> U512 {
+    check_role(roles::ROLE_TREASURER);
+    multisig::check_multisig_not_configured();
+
+    amend_snapshot(ver1_address, new_balance, swap_storage::load_admin())
+}
+
+// The refund never goes to the caller, so that an operator or a signer
+// cannot move funds of the contract purse to itself.
+fn amend_snapshot(ver1_address: String, new_balance: U512, refund_recipient: PublicKey) -> U512 {
+    check_not_swept();
+    check_ver1_address(&ver1_address, &swap_storage::load_ver1_network_params());
+    if !swap_storage::check_snapshot_data_existence(&ver1_address) {
+        runtime::revert(SwapError::SnapshotRecordNotFound);
+    }
+
+    let mut data = swap_storage::load_snapshot_data(ver1_address.clone());
+    if data.is_swapped != U512::from(0) {
+        runtime::revert(SwapError::AlreadySwapProceeded);
+    }
+
+    let prev_balance = data.prev_balance;
+    if new_balance < prev_balance {
+        refund_liability(refund_recipient, prev_balance - new_balance);
+    }
+
+    data.prev_balance = new_balance;
+    swap_storage::save_snapshot_data(ver1_address, data);
+
+    let total_liabilities = swap_storage::load_total_liabilities();
+    swap_storage::save_total_liabilities(total_liabilities + new_balance - prev_balance);
+
+    prev_balance
+}
+
+// Total amount is the sum of every leaf. It should be funded before the root is inserted,
+// so that the purse covers it on top of the outstanding liabilities.
+pub fn insert_snapshot_merkle_root(merkle_root: String, total_amount: U512) {
+    check_role(roles::ROLE_SNAPSHOT_OPERATOR);
+    check_not_swept();
+
+    if swap_storage::check_snapshot_merkle_root_existence() {
+        runtime::revert(SwapError::MerkleRootAlreadyRegistered);
+    }
+
+    // Normalize to lowercase hex after validation
+    let merkle_root = hex::encode(merkle::decode_hash(merkle_root));
+    check_funded(total_amount);
+
+    swap_storage::save_snapshot_merkle_root(merkle_root.clone());
+    swap_storage::save_snapshot_merkle_total(total_amount);
+    swap_storage::save_total_liabilities(swap_storage::load_total_liabilities() + total_amount);
+    stats::count_merkle_snapshot(total_amount);
+    events::emit(SwapEvent::SnapshotMerkleRootInserted {
+        merkle_root,
+        amount: total_amount,
+    });
+}
+
+// Fixes a wrong root before any leaf is claimed.
+// Returns the total before replacement, so that the caller can fund the difference.
+// If the total decreases, the difference is refunded to the admin from the contract purse.
+pub fn replace_snapshot_merkle_root(merkle_root: String, total_amount: U512) -> U512 {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    replace_merkle_root(merkle_root, total_amount, swap_storage::load_admin())
+}
+
+fn replace_merkle_root(
+    merkle_root: String,
+    total_amount: U512,
+    refund_recipient: PublicKey,
+) -> U512 {
+    check_not_swept();
+    if !swap_storage::check_snapshot_merkle_root_existence() {
+        runtime::revert(SwapError::MerkleRootNotRegistered);
+    }
+    if swap_storage::load_snapshot_merkle_claimed_leaves() != U512::from(0) {
+        runtime::revert(SwapError::MerkleLeafAlreadyClaimed);
+    }
+
+    let merkle_root = hex::encode(merkle::decode_hash(merkle_root));
+    let prev_total = swap_storage::load_snapshot_merkle_total();
+    if total_amount < prev_total {
+        refund_liability(refund_recipient, prev_total - total_amount);
+    }
+
+    swap_storage::save_snapshot_merkle_root(merkle_root.clone());
+    swap_storage::save_snapshot_merkle_total(total_amount);
+    let total_liabilities = swap_storage::load_total_liabilities();
+    swap_storage::save_total_liabilities(total_liabilities + total_amount - prev_total);
+    stats::count_merkle_snapshot(total_amount);
+    events::emit(SwapEvent::SnapshotMerkleRootReplaced {
+        merkle_root,
+        amount: total_amount,
+    });
+
+    prev_total
+}
+
+// The new admin takes over only after accepting it by itself,
+// so that the admin cannot be handed to a mistyped or unusable key.
+// Proposing again replaces the previous proposal.
+pub fn propose_admin(new_admin: PublicKey) {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    swap_storage::save_pending_admin(new_admin);
+}
+
+// Stops every claim while an incident is investigated.
+// Admin and query methods keep working.
+// It does not wait for approvals, so that the admin or any single signer can stop claims at once.
+// Resuming is still behind a proposal, once the signers are configured.
+pub fn pause_swap() {
+    let curr_account = runtime::get_caller();
+    if curr_account != swap_storage::load_admin() && !multisig::is_multisig_signer(curr_account) {
+        runtime::revert(SwapError::NotAdmin);
+    }
+
+    swap_storage::save_paused(true);
+}
+
+pub fn unpause_swap() {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    swap_storage::save_paused(false);
+}
+
+// Block time in milliseconds. Zero leaves that side of the window open.
+pub fn set_claim_window(claim_start: u64, claim_end: u64) {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    apply_claim_window(claim_start, claim_end);
+}
+
+fn apply_claim_window(claim_start: u64, claim_end: u64) {
+    // Reopening the window after the sweep leaves claims without funds
+    check_not_swept();
+    if claim_end != 0 && claim_end <= claim_start {
+        runtime::revert(SwapError::InvalidClaimWindow);
+    }
+
+    swap_storage::save_claim_window(claim_start, claim_end);
+}
+
+// Returns the swept amount.
+pub fn sweep_unclaimed(target: Key) -> U512 {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    sweep_contract_purse(target)
+}
+
+// Moves the rest of the contract purse to the treasury after the deadline.
+// Unclaimed snapshot records and merkle leaves are expired from then on,
+// and cannot be claimed or amended.
+fn sweep_contract_purse(target: Key) -> U512 {
+    let (_, claim_end) = swap_storage::load_claim_window();
+    let now: u64 = runtime::get_blocktime().into();
+    if claim_end == 0 || now < claim_end {
+        runtime::revert(SwapError::SweepNotAllowed);
+    }
+    check_not_swept();
+
+    let contract_purse = swap_storage::load_contract_wallet();
+    let amount = system::get_balance(contract_purse).unwrap_or_revert();
+    if amount > U512::from(0) {
+        let transfer_res: TransferResult = match target {
+            Key::Account(account) => {
+                system::transfer_from_purse_to_account(contract_purse, account, amount)
+            }
+            Key::URef(purse) => system::transfer_from_purse_to_purse(contract_purse, purse, amount),
+            _ => runtime::revert(SwapError::InvalidSweepTarget),
+        };
+
+        if let Err(err) = transfer_res {
+            runtime::revert(err);
+        }
+    }
+
+    swap_storage::save_total_expired(outstanding_liabilities());
+    if swap_storage::check_snapshot_merkle_root_existence() {
+        swap_storage::save_snapshot_merkle_expired(
+            swap_storage::load_snapshot_merkle_total()
+                .saturating_sub(swap_storage::load_snapshot_merkle_claimed_amount()),
+        );
+    }
+    swap_storage::save_swept();
+    amount
+}
+
+pub fn grant_role(role: String, account: PublicKey) {
+    check_admin();
+    multisig::check_multisig_not_configured();
+    validate_role(&role);
+
+    swap_storage::save_role(&role, account);
+}
+
+pub fn revoke_role(role: String, account: PublicKey) {
+    check_admin();
+    multisig::check_multisig_not_configured();
+    validate_role(&role);
+
+    remove_granted_role(&role, account);
+}
+
+fn remove_granted_role(role: &str, account: PublicKey) {
+    if !swap_storage::check_role(role, account) {
+        runtime::revert(SwapError::RoleNotGranted);
+    }
+    swap_storage::remove_role(role, account);
+}
+
+// Exchange and burned wallets are denied, so that their balances are not claimed
+// by whoever holds the keys. Duplicates are ignored.
+pub fn add_to_denylist(ver1_addresses: Vec<String>) {
+    check_role(roles::ROLE_SNAPSHOT_OPERATOR);
+
+    if ver1_addresses.is_empty() {
+        runtime::revert(SwapError::InvalidNumOfDenylistEntries);
+    }
+
+    let network_params = swap_storage::load_ver1_network_params();
+    for ver1_address in ver1_addresses {
+        check_ver1_address(&ver1_address, &network_params);
+        if swap_storage::check_denylisted(&ver1_address) {
+            continue;
+        }
+
+        swap_storage::save_denylisted(&ver1_address);
+        events::emit(SwapEvent::DenylistAdded { ver1_address });
+    }
+}
+
+// Only the admin can let a denied address claim again
+pub fn remove_from_denylist(ver1_address: String) {
+    check_admin();
+    multisig::check_multisig_not_configured();
+
+    remove_denylist_entry(ver1_address);
+}
+
+fn remove_denylist_entry(ver1_address: String) {
+    if !swap_storage::check_denylisted(&ver1_address) {
+        runtime::revert(SwapError::NotDenylisted);
+    }
+    swap_storage::remove_denylisted(&ver1_address);
+    events::emit(SwapEvent::DenylistRemoved { ver1_address });
+}
+
+pub fn accept_admin() {
+    let curr_account = runtime::get_caller();
+    if !swap_storage::check_pending_admin_existence()
+        || curr_account != swap_storage::load_pending_admin()
+    {
+        runtime::revert(SwapError::NotPendingAdmin);
+    }
+
+    let prev_admin = swap_storage::load_admin();
+    swap_storage::save_admin(curr_account);
+    swap_storage::remove_pending_admin();
+    events::emit(SwapEvent::AdminChanged {
+        prev_admin,
+        new_admin: curr_account,
+    });
 }
 
 pub fn get_contract_purse() -> URef {
@@ -41,111 +403,958 @@ pub fn get_contract_purse() -> URef {
     URef::new(purse.addr(), AccessRights::READ_ADD)
 }
 
-pub fn insert_kyc_data(new_mainnet_address: PublicKey, kyc_level: U512) {
-    if runtime::get_caller() != swap_storage::load_admin() {
-        runtime::revert(SwapError::NotAdmin);
-    }
+// expires_at is a block time, or 0 for an approval without expiry
+pub fn insert_kyc_data(new_mainnet_address: PublicKey, kyc_level: U512, expires_at: u64) {
+    check_role(roles::ROLE_KYC_OPERATOR);
 
     if swap_storage::check_kyc_data_existence(new_mainnet_address) {
         runtime::revert(SwapError::AlreadyRegisteredAndReceivedSmallToken);
     }
+    kyc_tier::load_active_kyc_tier(kyc_level);
+    check_kyc_expiry(expires_at);
 
     let new_data = UnitKYCData {
         kyc_level,
         swapped_amount: U512::from(0),
+        period_start: 0,
+        period_swapped_amount: U512::from(0),
+        expires_at,
+        status: KYCStatus::Active,
     };
     swap_storage::save_kyc_data(new_mainnet_address, new_data);
+    swap_storage::index_kyc_account(new_mainnet_address);
+    stats::count_kyc_registration(kyc_level);
+    events::emit(SwapEvent::KycRegistered {
+        mainnet_address: new_mainnet_address,
+        kyc_level,
+    });
 }
 
-pub fn update_kyc_level(new_mainnet_address: PublicKey, kyc_level: U512) {
-    if runtime::get_caller() != swap_storage::load_admin() {
-        runtime::revert(SwapError::NotAdmin);
-    }
+// Without expires_at, the expiry of the record is kept
+pub fn update_kyc_level(new_mainnet_address: PublicKey, kyc_level: U512, expires_at: Option<u64>) {
+    check_role(roles::ROLE_KYC_OPERATOR);
+    kyc_tier::load_active_kyc_tier(kyc_level);
 
     let mut curr_data = swap_storage::load_kyc_data(new_mainnet_address);
+    check_kyc_not_revoked(&curr_data);
+    stats::count_kyc_level_change(curr_data.kyc_level, kyc_level);
+    events::emit(SwapEvent::KycLevelChanged {
+        mainnet_address: new_mainnet_address,
+        prev_kyc_level: curr_data.kyc_level,
+        kyc_level,
+    });
+
     curr_data.kyc_level = kyc_level;
+    if let Some(expires_at) = expires_at {
+        check_kyc_expiry(expires_at);
+        curr_data.expires_at = expires_at;
+        swap_storage::index_kyc_account(new_mainnet_address);
+    }
     swap_storage::save_kyc_data(new_mainnet_address, curr_data);
+
+    // The unpaid remainders of partial claims are paid now, up to the allowance of the new tier.
+    // While claims are not available, the user claims them later with claim_pending.
+    if claim_unavailable_reason().is_none() && kyc_unavailable_reason(&curr_data).is_none() {
+        release_pending_claims(new_mainnet_address, curr_data);
+    }
+}
+
+// Extends or shortens the approval after re-verification, without changing the level
+pub fn renew_kyc(mainnet_address: PublicKey, expires_at: u64) {
+    check_role(roles::ROLE_KYC_OPERATOR);
+
+    if !swap_storage::check_kyc_data_existence(mainnet_address) {
+        runtime::revert(SwapError::NotRegisteredKYC);
+    }
+    check_kyc_expiry(expires_at);
+
+    let mut curr_data = swap_storage::load_kyc_data(mainnet_address);
+    check_kyc_not_revoked(&curr_data);
+    curr_data.expires_at = expires_at;
+    swap_storage::save_kyc_data(mainnet_address, curr_data);
+    swap_storage::index_kyc_account(mainnet_address);
+
+    events::emit(SwapEvent::KycRenewed {
+        mainnet_address,
+        expires_at,
+    });
+}
+
+// Revoked account cannot claim nor be updated anymore.
+// The record, including swapped_amount, is kept for auditing.
+pub fn revoke_kyc(mainnet_address: PublicKey) {
+    let curr_data = load_kyc_data_for_status_change(mainnet_address);
+    check_kyc_not_revoked(&curr_data);
+
+    save_kyc_status(mainnet_address, curr_data, KYCStatus::Revoked);
+}
+
+// Suspends claims of the account until unfreeze_account
+pub fn freeze_account(mainnet_address: PublicKey) {
+    let curr_data = load_kyc_data_for_status_change(mainnet_address);
+    check_kyc_not_revoked(&curr_data);
+    if curr_data.status == KYCStatus::Frozen {
+        runtime::revert(SwapError::AccountFrozen);
+    }
+
+    save_kyc_status(mainnet_address, curr_data, KYCStatus::Frozen);
+}
+
+pub fn unfreeze_account(mainnet_address: PublicKey) {
+    let curr_data = load_kyc_data_for_status_change(mainnet_address);
+    if curr_data.status != KYCStatus::Frozen {
+        runtime::revert(SwapError::AccountNotFrozen);
+    }
+
+    save_kyc_status(mainnet_address, curr_data, KYCStatus::Active);
+}
+
+fn load_kyc_data_for_status_change(mainnet_address: PublicKey) -> UnitKYCData {
+    check_role(roles::ROLE_KYC_OPERATOR);
+
+    if !swap_storage::check_kyc_data_existence(mainnet_address) {
+        runtime::revert(SwapError::NotRegisteredKYC);
+    }
+    swap_storage::load_kyc_data(mainnet_address)
+}
+
+fn save_kyc_status(mainnet_address: PublicKey, mut curr_data: UnitKYCData, status: KYCStatus) {
+    curr_data.status = status;
+    swap_storage::save_kyc_data(mainnet_address, curr_data);
+
+    events::emit(SwapEvent::KycStatusChanged {
+        mainnet_address,
+        status,
+    });
 }
 
 // user features
 
+// With allow_partial, a user over the cap or the period limit of the tier is paid
+// up to the remaining allowance instead of reverting,
+// and the unpaid remainder is recorded against each ver1 record.
 pub fn validate_sign_and_update_swapped_amount(
     ver1_pubkey_hex: Vec<String>,
-    message: Vec<String>,
     signature_hex: Vec<String>,
+    allow_partial: bool,
 ) {
-    if !(ver1_pubkey_hex.len() == message.len() && ver1_pubkey_hex.len() == signature_hex.len()) {
+    check_claim_available();
+
+    if ver1_pubkey_hex.len() != signature_hex.len() {
         runtime::revert(SwapError::InsufficientNumOfSwapParams);
     }
 
     // Get stored values
     let curr_account = runtime::get_caller();
-    let kyc_border_allowance_cap = swap_storage::load_kyc_border_allowance_cap();
+    let curr_user_kyc_data = load_claimer_kyc_data(curr_account);
+    let network_params = swap_storage::load_ver1_network_params();
+
+    // Iterate addresses and summize for total value
+    let mut prev_amount_for_whole_address = U512::from(0);
+    let mut claimed_records: Vec<(String, U512)> = Vec::new();
+    for (pubkey, signature) in ver1_pubkey_hex.iter().zip(signature_hex.iter()) {
+        let address = derive_ver1_address(pubkey.to_string(), &network_params);
+        check_not_denylisted(&address);
+        verify_ver1_signature(curr_account, pubkey, &address, signature);
+
+        let amount = mark_snapshot_as_swapped(address.clone());
+        prev_amount_for_whole_address += amount;
+        claimed_records.push((address, amount));
+    }
 
-    let mut curr_user_kyc_data = swap_storage::load_kyc_data(curr_account);
+    let tier = kyc_tier::load_active_kyc_tier(curr_user_kyc_data.kyc_level);
+    let now: u64 = runtime::get_blocktime().into();
+    if allow_partial
+        && kyc_tier::claim_limit_error(
+            &tier,
+            &curr_user_kyc_data,
+            prev_amount_for_whole_address,
+            now,
+        )
+        .is_some()
+    {
+        let remaining_allowance = kyc_tier::remaining_allowance(&tier, &curr_user_kyc_data, now)
+            .unwrap_or(prev_amount_for_whole_address);
+        let num_of_records = claimed_records.len();
+        let amounts: Vec<U512> = claimed_records.iter().map(|(_, amount)| *amount).collect();
+        let paid_amounts = allocate_partial_claim(remaining_allowance, &amounts);
+
+        let mut pending_claims = swap_storage::load_pending_claims(curr_account);
+        let mut paid_records: Vec<(String, U512)> = Vec::new();
+        let mut paid_amount_for_whole_address = U512::from(0);
+        for ((ver1_address, amount), paid_amount) in claimed_records.into_iter().zip(paid_amounts) {
+            if paid_amount < amount {
+                let mut data = swap_storage::load_snapshot_data(ver1_address.clone());
+                data.pending_amount = amount - paid_amount;
+                swap_storage::save_snapshot_data(ver1_address.clone(), data);
+                pending_claims.push(ver1_address.clone());
+            }
+            // An address paid nothing gets its receipt when the remainder is released
+            if paid_amount > U512::from(0) {
+                paid_amount_for_whole_address += paid_amount;
+                paid_records.push((ver1_address, paid_amount));
+            }
+        }
+        swap_storage::save_pending_claims(curr_account, pending_claims);
+
+        settle_payout(
+            curr_account,
+            curr_user_kyc_data,
+            num_of_records,
+            paid_amount_for_whole_address,
+        );
+        record_receipts(curr_account, paid_records);
+        return;
+    }
+
+    transfer_swapped_amount(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records,
+        prev_amount_for_whole_address,
+    );
+}
+
+pub fn validate_signed_message_and_update_swapped_amount(
+    message: Vec<String>,
+    signature_base64: Vec<String>,
+) {
+    check_claim_available();
+
+    if message.len() != signature_base64.len() {
+        runtime::revert(SwapError::InsufficientNumOfSwapParams);
+    }
+
+    // Get stored values
+    let curr_account = runtime::get_caller();
+    let curr_user_kyc_data = load_claimer_kyc_data(curr_account);
+    let contract_hash = swap_storage::load_contract_hash();
+    let curr_account_hex = swap_storage::to_hex_string(curr_account);
+    let network_params = swap_storage::load_ver1_network_params();
 
     // Iterate addresses and summize for total value
     let mut prev_amount_for_whole_address = U512::from(0);
-    for pubkey in &ver1_pubkey_hex {
-        let address = derive_ver1_address(pubkey.to_string());
-        let mut data = swap_storage::load_snapshot_data(address.clone());
+    let mut claimed_records: Vec<(String, U512)> = Vec::new();
+    for (message, signature) in message.iter().zip(signature_base64.into_iter()) {
+        // Signer of the message is the owner of the address
+        let ver1_pubkey_hex = recover_ver1_pubkey(message, signature);
+        let address = derive_ver1_address(ver1_pubkey_hex, &network_params);
+        check_not_denylisted(&address);
 
-        // Check this wallet is proceeded swap or not
-        if data.is_swapped != U512::from(0) {
-            runtime::revert(SwapError::AlreadySwapProceeded);
+        // Plaintext should be exactly the swap message of this claim
+        if *message != swap_message(contract_hash, &curr_account_hex, &address) {
+            runtime::revert(SwapError::SwapMessageMismatch);
         }
 
-        // If not proceeded, summize swappable amount, and mark as proceeded
-        prev_amount_for_whole_address += data.prev_balance;
-        data.is_swapped = U512::from(1);
-        swap_storage::save_snapshot_data(address.clone(), data);
+        let amount = mark_snapshot_as_swapped(address.clone());
+        prev_amount_for_whole_address += amount;
+        claimed_records.push((address, amount));
     }
 
-    let kyc_level_in_primitive_type: u64 = curr_user_kyc_data.kyc_level.as_();
-    let swappable_amount = match kyc_level_in_primitive_type {
-        1u64 => {
-            if curr_user_kyc_data.swapped_amount + prev_amount_for_whole_address
-                >= kyc_border_allowance_cap
-            {
-                runtime::revert(SwapError::ExceededSwapRange)
-            } else {
-                prev_amount_for_whole_address
-            }
+    transfer_swapped_amount(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records,
+        prev_amount_for_whole_address,
+    );
+}
+
+pub fn validate_sign_and_claim_with_merkle_proof(
+    ver1_pubkey_hex: Vec<String>,
+    signature_hex: Vec<String>,
+    leaf_index: Vec<u64>,
+    prev_balance: Vec<U512>,
+    merkle_proof: Vec<Vec<String>>,
+) {
+    check_claim_available();
+
+    let num_of_params = ver1_pubkey_hex.len();
+    if !(signature_hex.len() == num_of_params
+        && leaf_index.len() == num_of_params
+        && prev_balance.len() == num_of_params
+        && merkle_proof.len() == num_of_params)
+    {
+        runtime::revert(SwapError::InsufficientNumOfSwapParams);
+    }
+
+    if !swap_storage::check_snapshot_merkle_root_existence() {
+        runtime::revert(SwapError::MerkleRootNotRegistered);
+    }
+
+    // Get stored values
+    let curr_account = runtime::get_caller();
+    let curr_user_kyc_data = load_claimer_kyc_data(curr_account);
+    let merkle_root = merkle::decode_hash(swap_storage::load_snapshot_merkle_root());
+    let network_params = swap_storage::load_ver1_network_params();
+
+    // Verify each (address, balance) leaf against the root and summize for total value
+    let mut prev_amount_for_whole_address = U512::from(0);
+    let mut claimed_records: Vec<(String, U512)> = Vec::new();
+    for i in 0..num_of_params {
+        let address = derive_ver1_address(ver1_pubkey_hex[i].clone(), &network_params);
+        check_not_denylisted(&address);
+        verify_ver1_signature(
+            curr_account,
+            &ver1_pubkey_hex[i],
+            &address,
+            &signature_hex[i],
+        );
+
+        let leaf = merkle::leaf_hash(leaf_index[i], &address, prev_balance[i]);
+        let proof = merkle::decode_proof(merkle_proof[i].clone());
+        if !merkle::verify_proof(&merkle_root, leaf, leaf_index[i], &proof) {
+            runtime::revert(SwapError::InvalidMerkleProof);
+        }
+
+        // Check this leaf, or the same address in another leaf or in a snapshot record,
+        // is claimed or not
+        if swap_storage::check_merkle_leaf_claimed(leaf_index[i])
+            || swap_storage::check_merkle_address_claimed(&address)
+            || (swap_storage::check_snapshot_data_existence(&address)
+                && swap_storage::load_snapshot_data(address.clone()).is_swapped != U512::from(0))
+        {
+            runtime::revert(SwapError::AlreadySwapProceeded);
         }
-        2u64 => prev_amount_for_whole_address,
-        _ => runtime::revert(SwapError::InvalidKYCLevelValue),
-    };
 
-    // Sign verification
-    for i in 0..ver1_pubkey_hex.len() {
-        if !signature_verification(
-            ver1_pubkey_hex[i].clone(),
-            message[i].clone(),
-            signature_hex[i].clone(),
+        prev_amount_for_whole_address += prev_balance[i];
+        swap_storage::mark_merkle_leaf_claimed(leaf_index[i]);
+        swap_storage::mark_merkle_address_claimed(&address);
+        swap_storage::save_snapshot_merkle_claimed_leaves(
+            swap_storage::load_snapshot_merkle_claimed_leaves() + U512::from(1),
+        );
+        swap_storage::save_snapshot_merkle_claimed_amount(
+            swap_storage::load_snapshot_merkle_claimed_amount() + prev_balance[i],
+        );
+        claimed_records.push((address, prev_balance[i]));
+    }
+    stats::count_claimed_merkle_leaves(num_of_params);
+
+    transfer_swapped_amount(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records,
+        prev_amount_for_whole_address,
+    );
+}
+
+pub fn validate_multisig_and_update_swapped_amount(
+    redeem_script_hex: Vec<String>,
+    signature_hex: Vec<Vec<String>>,
+) {
+    check_claim_available();
+
+    if redeem_script_hex.len() != signature_hex.len() {
+        runtime::revert(SwapError::InsufficientNumOfSwapParams);
+    }
+
+    // Get stored values
+    let curr_account = runtime::get_caller();
+    let curr_user_kyc_data = load_claimer_kyc_data(curr_account);
+    let contract_hash = swap_storage::load_contract_hash();
+    let curr_account_hex = swap_storage::to_hex_string(curr_account);
+    let network_params = swap_storage::load_ver1_network_params();
+
+    // Iterate P2SH addresses and summize for total value
+    let mut prev_amount_for_whole_address = U512::from(0);
+    let mut claimed_records: Vec<(String, U512)> = Vec::new();
+    for (redeem_script, signatures) in redeem_script_hex.into_iter().zip(signature_hex.into_iter())
+    {
+        let redeem_script_bytes = match hex::decode(redeem_script) {
+            Ok(val) => val,
+            Err(_) => runtime::revert(SwapError::RedeemScriptHexDecodeFail),
+        };
+        let (required_signatures, ver1_pubkeys) =
+            parse_multisig_redeem_script(&redeem_script_bytes);
+        let address = derive_ver1_p2sh_address(&redeem_script_bytes, &network_params);
+        check_not_denylisted(&address);
+
+        let message = swap_message(contract_hash, &curr_account_hex, &address);
+        if !multisig_verification(
+            &ver1_pubkeys,
+            required_signatures,
+            swap_message_digest(&message),
+            signatures,
         ) {
             runtime::revert(SwapError::InvalidSignature);
         }
+
+        let amount = mark_snapshot_as_swapped(address.clone());
+        prev_amount_for_whole_address += amount;
+        claimed_records.push((address, amount));
     }
 
-    let contract_purse = swap_storage::load_contract_wallet();
-    let transfer_res: TransferResult = system::transfer_from_purse_to_account(
-        contract_purse,
-        runtime::get_caller(),
-        swappable_amount,
+    transfer_swapped_amount(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records,
+        prev_amount_for_whole_address,
+    );
+}
+
+// Pays the unpaid remainders of the partial claims of the caller,
+// up to the remaining allowance of the current tier. The rest stays pending.
+pub fn claim_pending() {
+    check_claim_available();
+
+    let curr_account = runtime::get_caller();
+    let curr_user_kyc_data = load_claimer_kyc_data(curr_account);
+    if swap_storage::load_pending_claims(curr_account).is_empty() {
+        runtime::revert(SwapError::NoPendingClaims);
+    }
+
+    release_pending_claims(curr_account, curr_user_kyc_data);
+}
+
+// Query features
+
+pub fn get_snapshot_record(ver1_address: String) -> BTreeMap<String, String> {
+    // Snapshot records share the named keys of the contract, such as "admin".
+    // Only a valid address can be looked up as a record.
+    check_ver1_address(&ver1_address, &swap_storage::load_ver1_network_params());
+    if !swap_storage::check_snapshot_data_existence(&ver1_address) {
+        runtime::revert(SwapError::SnapshotRecordNotFound);
+    }
+
+    let data = swap_storage::load_snapshot_data(ver1_address);
+    let is_expired = swap_storage::load_swept()
+        && (data.is_swapped == U512::from(0) || data.pending_amount > U512::from(0));
+
+    let mut res = data.organize();
+    res.insert(
+        String::from(keys::KEY_IS_EXPIRED),
+        String::from(if is_expired { "1" } else { "0" }),
     );
+    res
+}
+
+// Scans the KYC accounts from the index `from`, at most `limit` and MAX_KYC_RECORDS_PER_QUERY,
+// and returns the records which expire until `expires_before`, including expired ones.
+// Revoked records are skipped, as they cannot be renewed.
+// Query again from `from` + `limit` for the next page.
+pub fn get_expiring_kyc_records(
+    expires_before: u64,
+    from: U512,
+    limit: U512,
+) -> Vec<BTreeMap<String, String>> {
+    let kyc_account_count = swap_storage::load_kyc_account_count();
+    let limit = if limit > U512::from(MAX_KYC_RECORDS_PER_QUERY) {
+        U512::from(MAX_KYC_RECORDS_PER_QUERY)
+    } else {
+        limit
+    };
+
+    let mut res = Vec::new();
+    let mut seq = if from == U512::from(0) {
+        U512::from(1)
+    } else {
+        from
+    };
+    let end = seq + limit;
+    while seq <= kyc_account_count && seq < end {
+        let mainnet_address = swap_storage::load_kyc_account(seq);
+        let data = swap_storage::load_kyc_data(mainnet_address);
+        if data.status != KYCStatus::Revoked
+            && data.expires_at != 0
+            && data.expires_at <= expires_before
+        {
+            let mut record = data.organize();
+            record.insert(
+                keys::KEY_KYC_MAINNET_ADDRESS.to_string(),
+                swap_storage::to_hex_string(mainnet_address),
+            );
+            res.push(record);
+        }
+        seq += U512::from(1);
+    }
+    res
+}
+
+pub fn is_denylisted(ver1_address: String) -> bool {
+    swap_storage::check_denylisted(&ver1_address)
+}
+
+pub fn get_kyc_record(mainnet_address: PublicKey) -> BTreeMap<String, String> {
+    if !swap_storage::check_kyc_data_existence(mainnet_address) {
+        runtime::revert(SwapError::NotRegisteredKYC);
+    }
+
+    swap_storage::load_kyc_data(mainnet_address).organize()
+}
+
+pub fn get_receipt(ver1_address: String) -> BTreeMap<String, String> {
+    if !swap_storage::check_receipt_existence(&ver1_address) {
+        runtime::revert(SwapError::ReceiptNotFound);
+    }
+
+    swap_storage::load_receipt(&ver1_address).organize()
+}
+
+pub fn get_receipts_by_account(mainnet_address: PublicKey) -> Vec<BTreeMap<String, String>> {
+    swap_storage::load_account_receipts(mainnet_address)
+        .iter()
+        .map(|ver1_address| swap_storage::load_receipt(ver1_address).organize())
+        .collect()
+}
+
+pub fn get_allowance_cap() -> U512 {
+    swap_storage::load_kyc_border_allowance_cap()
+}
+
+pub fn get_admin() -> PublicKey {
+    swap_storage::load_admin()
+}
+
+pub fn get_reserve_status() -> BTreeMap<String, String> {
+    let purse_balance =
+        system::get_balance(swap_storage::load_contract_wallet()).unwrap_or_revert();
+
+    let mut res: BTreeMap<String, String> = BTreeMap::new();
+    res.insert(
+        String::from(keys::KEY_TOTAL_LIABILITIES),
+        swap_storage::load_total_liabilities().to_string(),
+    );
+    res.insert(
+        String::from(keys::KEY_TOTAL_CLAIMED),
+        swap_storage::load_total_claimed().to_string(),
+    );
+    res.insert(
+        String::from(keys::KEY_TOTAL_EXPIRED),
+        swap_storage::load_total_expired().to_string(),
+    );
+    res.insert(
+        String::from(keys::KEY_OUTSTANDING),
+        outstanding_liabilities().to_string(),
+    );
+    res.insert(
+        String::from(keys::KEY_PURSE_BALANCE),
+        purse_balance.to_string(),
+    );
+    res.insert(
+        String::from(keys::KEY_SWEPT),
+        String::from(if swap_storage::load_swept() { "1" } else { "0" }),
+    );
+
+    // Part of the above which belongs to the merkle snapshot
+    if swap_storage::check_snapshot_merkle_root_existence() {
+        res.insert(
+            String::from(keys::KEY_SNAPSHOT_MERKLE_TOTAL),
+            swap_storage::load_snapshot_merkle_total().to_string(),
+        );
+        res.insert(
+            String::from(keys::KEY_SNAPSHOT_MERKLE_CLAIMED_AMOUNT),
+            swap_storage::load_snapshot_merkle_claimed_amount().to_string(),
+        );
+        res.insert(
+            String::from(keys::KEY_SNAPSHOT_MERKLE_EXPIRED),
+            swap_storage::load_snapshot_merkle_expired().to_string(),
+        );
+    }
+    res
+}
+
+fn load_claimer_kyc_data(curr_account: PublicKey) -> UnitKYCData {
+    let curr_user_kyc_data = swap_storage::load_kyc_data(curr_account);
+    if let Some(err) = kyc_unavailable_reason(&curr_user_kyc_data) {
+        runtime::revert(err);
+    }
+    curr_user_kyc_data
+}
+
+fn kyc_unavailable_reason(kyc_data: &UnitKYCData) -> Option<SwapError> {
+    match kyc_data.status {
+        KYCStatus::Revoked => return Some(SwapError::KYCRevoked),
+        KYCStatus::Frozen => return Some(SwapError::AccountFrozen),
+        KYCStatus::Active => (),
+    }
+
+    let now: u64 = runtime::get_blocktime().into();
+    if kyc_data.expires_at != 0 && now >= kyc_data.expires_at {
+        return Some(SwapError::KYCExpired);
+    }
+    None
+}
+
+fn check_kyc_not_revoked(kyc_data: &UnitKYCData) {
+    if kyc_data.status == KYCStatus::Revoked {
+        runtime::revert(SwapError::KYCRevoked);
+    }
+}
+
+fn check_kyc_expiry(expires_at: u64) {
+    let now: u64 = runtime::get_blocktime().into();
+    if expires_at != 0 && expires_at <= now {
+        runtime::revert(SwapError::InvalidKYCExpiry);
+    }
+}
+
+fn check_claim_available() {
+    if let Some(err) = claim_unavailable_reason() {
+        runtime::revert(err);
+    }
+}
+
+fn claim_unavailable_reason() -> Option<SwapError> {
+    // Funds of unclaimed records and leaves are already moved to the treasury
+    if swap_storage::load_swept() {
+        return Some(SwapError::AlreadySwept);
+    }
+    if swap_storage::load_paused() {
+        return Some(SwapError::Paused);
+    }
+
+    let (claim_start, claim_end) = swap_storage::load_claim_window();
+    let now: u64 = runtime::get_blocktime().into();
+    if now < claim_start {
+        return Some(SwapError::ClaimNotYetOpen);
+    }
+    if claim_end != 0 && now >= claim_end {
+        return Some(SwapError::ClaimClosed);
+    }
+    None
+}
+
+fn outstanding_liabilities() -> U512 {
+    swap_storage::load_total_liabilities()
+        .saturating_sub(swap_storage::load_total_claimed())
+        .saturating_sub(swap_storage::load_total_expired())
+}
+
+// The purse should still cover the liabilities of the others after the payout.
+// Settled liability is the part of the outstanding liabilities which the payout pays off.
+fn check_reserve(payout: U512, settled_liability: U512) {
+    let purse_balance =
+        system::get_balance(swap_storage::load_contract_wallet()).unwrap_or_revert();
+    let remaining_liabilities = outstanding_liabilities().saturating_sub(settled_liability);
+
+    if purse_balance < payout || purse_balance - payout < remaining_liabilities {
+        runtime::revert(SwapError::InsufficientReserve);
+    }
+}
+
+// The amount should be already in the purse, on top of the outstanding liabilities
+fn check_funded(amount: U512) {
+    let purse_balance =
+        system::get_balance(swap_storage::load_contract_wallet()).unwrap_or_revert();
+
+    if purse_balance < outstanding_liabilities() + amount {
+        runtime::revert(SwapError::InsufficientReserve);
+    }
+}
+
+// Pays back a liability which is removed from the snapshot
+fn refund_liability(refund_recipient: PublicKey, amount: U512) {
+    check_reserve(amount, amount);
+
+    let contract_purse = swap_storage::load_contract_wallet();
+    let transfer_res: TransferResult =
+        system::transfer_from_purse_to_account(contract_purse, refund_recipient, amount);
 
     if let Err(err) = transfer_res {
         runtime::revert(err);
     }
+}
+
+fn check_not_swept() {
+    if swap_storage::load_swept() {
+        runtime::revert(SwapError::AlreadySwept);
+    }
+}
+
+fn check_admin() {
+    if runtime::get_caller() != swap_storage::load_admin() {
+        runtime::revert(SwapError::NotAdmin);
+    }
+}
+
+// Admin has every role
+fn check_role(role: &str) {
+    let curr_account = runtime::get_caller();
+    if curr_account != swap_storage::load_admin() && !swap_storage::check_role(role, curr_account) {
+        runtime::revert(SwapError::NoPermission);
+    }
+}
+
+fn validate_role(role: &str) {
+    match role {
+        roles::ROLE_KYC_OPERATOR | roles::ROLE_SNAPSHOT_OPERATOR | roles::ROLE_TREASURER => (),
+        _ => runtime::revert(SwapError::InvalidRole),
+    }
+}
+
+// A typo in the snapshot makes the record unclaimable, and locks its balance in the purse
+fn check_ver1_address(ver1_address: &str, network_params: &Ver1NetworkParams) {
+    if !validate_ver1_address(ver1_address, network_params) {
+        runtime::revert(SwapError::MalformedVer1Address);
+    }
+    if !verify_ver1_address_checksum(ver1_address, network_params) {
+        runtime::revert(SwapError::InvalidVer1AddressChecksum);
+    }
+}
+
+fn check_not_denylisted(ver1_address: &str) {
+    if swap_storage::check_denylisted(ver1_address) {
+        runtime::revert(SwapError::DeniedVer1Address);
+    }
+}
+
+// Marks the snapshot record as proceeded, and returns swappable amount of the record
+fn mark_snapshot_as_swapped(ver1_address: String) -> U512 {
+    // An address without a record has nothing to claim
+    if !swap_storage::check_snapshot_data_existence(&ver1_address) {
+        runtime::revert(SwapError::SnapshotRecordNotFound);
+    }
+
+    let mut data = swap_storage::load_snapshot_data(ver1_address.clone());
+
+    // Check this wallet is proceeded swap or not, including with a merkle proof
+    if data.is_swapped != U512::from(0) || swap_storage::check_merkle_address_claimed(&ver1_address)
+    {
+        runtime::revert(SwapError::AlreadySwapProceeded);
+    }
+
+    data.is_swapped = U512::from(1);
+    swap_storage::save_snapshot_data(ver1_address, data);
+
+    data.prev_balance
+}
+
+fn verify_ver1_signature(
+    curr_account: PublicKey,
+    ver1_pubkey_hex: &str,
+    ver1_address: &str,
+    signature_hex: &str,
+) {
+    // The message is never taken from the caller. It is rebuilt here,
+    // so the signature is valid only for this contract and this mainnet account.
+    let message = swap_message(
+        swap_storage::load_contract_hash(),
+        &swap_storage::to_hex_string(curr_account),
+        ver1_address,
+    );
+
+    if !signature_verification(
+        ver1_pubkey_hex.to_string(),
+        swap_message_digest(&message),
+        signature_hex.to_string(),
+    ) {
+        runtime::revert(SwapError::InvalidSignature);
+    }
+}
+
+fn transfer_swapped_amount(
+    curr_account: PublicKey,
+    curr_user_kyc_data: UnitKYCData,
+    claimed_records: Vec<(String, U512)>,
+    prev_amount_for_whole_address: U512,
+) {
+    let tier = kyc_tier::load_active_kyc_tier(curr_user_kyc_data.kyc_level);
+    let now: u64 = runtime::get_blocktime().into();
+    if let Some(err) = kyc_tier::claim_limit_error(
+        &tier,
+        &curr_user_kyc_data,
+        prev_amount_for_whole_address,
+        now,
+    ) {
+        runtime::revert(err);
+    }
+
+    pay_swapped_amount(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records,
+        prev_amount_for_whole_address,
+    );
+}
+
+fn pay_swapped_amount(
+    curr_account: PublicKey,
+    curr_user_kyc_data: UnitKYCData,
+    claimed_records: Vec<(String, U512)>,
+    swappable_amount: U512,
+) {
+    settle_payout(
+        curr_account,
+        curr_user_kyc_data,
+        claimed_records.len(),
+        swappable_amount,
+    );
+    record_receipts(curr_account, claimed_records);
+}
+
+// Pays the unpaid remainders of the partial claims of the account in order,
+// up to the remaining allowance of its current tier.
+// Remainders which go over the allowance stay pending.
+fn release_pending_claims(mainnet_address: PublicKey, curr_user_kyc_data: UnitKYCData) {
+    let pending_claims = swap_storage::load_pending_claims(mainnet_address);
+    if pending_claims.is_empty() {
+        return;
+    }
+
+    let pending_amounts: Vec<U512> = pending_claims
+        .iter()
+        .map(|ver1_address| swap_storage::load_snapshot_data(ver1_address.clone()).pending_amount)
+        .collect();
+    // Remainders of denylisted addresses stay pending, and take no share of the allowance
+    let releasable_amounts: Vec<U512> = pending_claims
+        .iter()
+        .zip(pending_amounts.iter())
+        .map(|(ver1_address, pending_amount)| {
+            if swap_storage::check_denylisted(ver1_address) {
+                U512::from(0)
+            } else {
+                *pending_amount
+            }
+        })
+        .collect();
+    let total_pending_amount = releasable_amounts
+        .iter()
+        .fold(U512::from(0), |acc, amount| acc + *amount);
+
+    let tier = kyc_tier::load_active_kyc_tier(curr_user_kyc_data.kyc_level);
+    let now: u64 = runtime::get_blocktime().into();
+    let remaining_allowance = kyc_tier::remaining_allowance(&tier, &curr_user_kyc_data, now)
+        .unwrap_or(total_pending_amount);
+    let released_amounts = allocate_partial_claim(remaining_allowance, &releasable_amounts);
+
+    let mut still_pending_claims: Vec<String> = Vec::new();
+    let mut unreceipted_records: Vec<(String, U512)> = Vec::new();
+    let mut released_amount = U512::from(0);
+    for ((ver1_address, pending_amount), released) in pending_claims
+        .into_iter()
+        .zip(pending_amounts)
+        .zip(released_amounts)
+    {
+        if released < pending_amount {
+            still_pending_claims.push(ver1_address.clone());
+        }
+        if released == U512::from(0) {
+            continue;
+        }
+
+        let mut data = swap_storage::load_snapshot_data(ver1_address.clone());
+        data.pending_amount = pending_amount - released;
+        swap_storage::save_snapshot_data(ver1_address.clone(), data);
+        released_amount += released;
+
+        // The partial claim paid nothing to the address, so it has no receipt yet
+        if !swap_storage::check_receipt_existence(&ver1_address) {
+            unreceipted_records.push((ver1_address, released));
+            continue;
+        }
+
+        let mut receipt = swap_storage::load_receipt(&ver1_address);
+        receipt.amount += released;
+        swap_storage::save_receipt(&ver1_address, &receipt);
+
+        events::emit(SwapEvent::TokensClaimed {
+            ver1_address,
+            mainnet_address,
+            amount: released,
+        });
+    }
+    swap_storage::save_pending_claims(mainnet_address, still_pending_claims);
+    if !unreceipted_records.is_empty() {
+        record_receipts(mainnet_address, unreceipted_records);
+    }
+
+    // The records are already counted as claimed at the partial claims
+    if released_amount > U512::from(0) {
+        settle_payout(mainnet_address, curr_user_kyc_data, 0, released_amount);
+    }
+}
+
+// Transfers the amount from the contract purse, and updates the KYC record and the totals
+fn settle_payout(
+    curr_account: PublicKey,
+    mut curr_user_kyc_data: UnitKYCData,
+    num_of_records: usize,
+    amount: U512,
+) {
+    check_reserve(amount, amount);
+
+    // A partial claim pays nothing, if the cap is already used up
+    if amount > U512::from(0) {
+        let contract_purse = swap_storage::load_contract_wallet();
+        let transfer_res: TransferResult =
+            system::transfer_from_purse_to_account(contract_purse, curr_account, amount);
+
+        if let Err(err) = transfer_res {
+            runtime::revert(err);
+        }
+    }
 
     // Update data
-    curr_user_kyc_data.swapped_amount += swappable_amount;
+    stats::count_claim(
+        curr_user_kyc_data.kyc_level,
+        num_of_records,
+        amount,
+        curr_user_kyc_data.swapped_amount == U512::from(0) && amount > U512::from(0),
+    );
+    curr_user_kyc_data.swapped_amount += amount;
+    kyc_tier::count_period_usage(
+        &mut curr_user_kyc_data,
+        amount,
+        runtime::get_blocktime().into(),
+    );
     swap_storage::save_kyc_data(curr_account, curr_user_kyc_data);
+    swap_storage::save_total_claimed(swap_storage::load_total_claimed() + amount);
+}
+
+// Splits the remaining cap over the records in order.
+// Returns the amount paid for each record.
+fn allocate_partial_claim(remaining_cap: U512, amounts: &[U512]) -> Vec<U512> {
+    let mut remaining_cap = remaining_cap;
+    amounts
+        .iter()
+        .map(|amount| {
+            let paid_amount = if *amount < remaining_cap {
+                *amount
+            } else {
+                remaining_cap
+            };
+            remaining_cap -= paid_amount;
+            paid_amount
+        })
+        .collect()
+}
+
+// Links each claimed ver1 address to the mainnet account which received its balance
+fn record_receipts(curr_account: PublicKey, claimed_records: Vec<(String, U512)>) {
+    let block_time: u64 = runtime::get_blocktime().into();
+    let mainnet_address = swap_storage::to_hex_string(curr_account);
+
+    let mut account_receipts = swap_storage::load_account_receipts(curr_account);
+    for (ver1_address, amount) in claimed_records {
+        let receipt_id = swap_storage::load_receipt_count() + U512::from(1);
+        swap_storage::save_receipt_count(receipt_id);
+
+        let receipt = UnitReceiptData {
+            receipt_id,
+            ver1_address: ver1_address.clone(),
+            mainnet_address: mainnet_address.clone(),
+            amount,
+            block_time,
+        };
+        swap_storage::save_receipt(&ver1_address, &receipt);
+        events::emit(SwapEvent::TokensClaimed {
+            ver1_address: ver1_address.clone(),
+            mainnet_address: curr_account,
+            amount,
+        });
+        account_receipts.push(ver1_address);
+    }
+    swap_storage::save_account_receipts(curr_account, account_receipts);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{derive_ver1_address, signature_verification};
+    use super::{
+        allocate_partial_claim, derive_ver1_address, derive_ver1_p2sh_address, merkle,
+        multisig_verification, parse_multisig_redeem_script, recover_ver1_pubkey,
+        signature_verification, swap_message, swap_message_digest, swap_storage::UnitKYCTierData,
+        validate_ver1_address, verify_ver1_address_checksum, Ver1NetworkParams,
+    };
+    use types::U512;
 
     #[test]
     pub fn test_should_verify_signature() {
@@ -161,16 +1370,341 @@ mod tests {
         assert_eq!(signature_verification(pubkey, message, signature), true);
     }
 
+    #[test]
+    pub fn test_should_verify_signature_bound_to_swap_message() {
+        let network_params = Ver1NetworkParams::default();
+        let pubkey = "02989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f";
+        let address = derive_ver1_address(String::from(pubkey), &network_params);
+        assert_eq!(address, "HMT6ZBkkjUFTguoycUaLuG1pjm83s8dfi2");
+
+        let signature = String::from(
+            "974bd01b963c61142c6c7d065c1dd54a421a341fbbf84e8ca9e97b81ce5857113c55f0277c5cead3\
+             df7f44ec7be43ab8518c7bed512ec50d5f04aa22e85194f5",
+        );
+        let mainnet_pubkey_hex = "02".repeat(32);
+
+        let message = swap_message([0x11u8; 32], &mainnet_pubkey_hex, &address);
+        assert_eq!(
+            swap_message_digest(&message),
+            "782f453eae4498a7ed9702763843bc2b7e0bb297084e5af79b88c5170c6aecf1"
+        );
+        assert_eq!(
+            signature_verification(
+                String::from(pubkey),
+                swap_message_digest(&message),
+                signature.clone()
+            ),
+            true
+        );
+
+        // Same signature cannot be used by another mainnet account or another swap contract
+        let message = swap_message([0x11u8; 32], &"03".repeat(32), &address);
+        assert_eq!(
+            signature_verification(
+                String::from(pubkey),
+                swap_message_digest(&message),
+                signature.clone()
+            ),
+            false
+        );
+        let message = swap_message([0x12u8; 32], &mainnet_pubkey_hex, &address);
+        assert_eq!(
+            signature_verification(
+                String::from(pubkey),
+                swap_message_digest(&message),
+                signature
+            ),
+            false
+        );
+    }
+
+    #[test]
+    pub fn test_should_verify_signature_of_uncompressed_pubkey() {
+        let network_params = Ver1NetworkParams::default();
+        // Same secret key with the compressed one above, serialized in 65 bytes
+        let pubkey = "04989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f\
+                      631f4d05b3ae518776ee08755a7703e64b2ebc32547504de0b55a142d4ecdf80";
+        let address = derive_ver1_address(String::from(pubkey), &network_params);
+        assert_eq!(address, "HKar8qXrhJsSTF4gp5GzznvXspPQHZuSh6");
+
+        let signature = String::from(
+            "314c5d6a5ecaaf36a1ca64a225ae42ab2339cd850f6a8f87e3d21390b437662732e7da5af6f3f133\
+             141720031bac1755ad49edefc7a9badef3015c027633b601",
+        );
+        let message = swap_message([0x11u8; 32], &"02".repeat(32), &address);
+        assert_eq!(
+            signature_verification(
+                String::from(pubkey),
+                swap_message_digest(&message),
+                signature
+            ),
+            true
+        );
+    }
+
+    #[test]
+    pub fn test_should_recover_ver1_address_from_signed_message() {
+        let network_params = Ver1NetworkParams::default();
+        let mainnet_pubkey_hex = "02".repeat(32);
+
+        // Compressed key
+        let address = "HMT6ZBkkjUFTguoycUaLuG1pjm83s8dfi2";
+        let message = swap_message([0x11u8; 32], &mainnet_pubkey_hex, address);
+        let signature = String::from(
+            "IFMD8TBAP34IhrHrewfKbGdJJBt9IHCK2xPVuQdMh60Uf8ElPtI7YgdMWYuwJaMXmanr6Dmke+JX36ghaprbcvw=",
+        );
+        let pubkey = recover_ver1_pubkey(&message, signature);
+        assert_eq!(
+            pubkey,
+            "02989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f"
+        );
+        assert_eq!(derive_ver1_address(pubkey, &network_params), address);
+
+        // Uncompressed key of the same secret derives another address
+        let address = "HKar8qXrhJsSTF4gp5GzznvXspPQHZuSh6";
+        let message = swap_message([0x11u8; 32], &mainnet_pubkey_hex, address);
+        let signature = String::from(
+            "HJc6qyfl/orv3msL0oRFLKBqNGoCnueNqMEae56iKJ31Xkz4KY9xG0j+CWrydQHdAX83q3pwWfmPxm04CFNEaSo=",
+        );
+        let pubkey = recover_ver1_pubkey(&message, signature);
+        assert_eq!(pubkey.len(), 130);
+        assert_eq!(derive_ver1_address(pubkey, &network_params), address);
+    }
+
     #[test]
     pub fn test_should_derive_same_ver1_address() {
+        let network_params = Ver1NetworkParams::default();
         let pubkey1 =
             String::from("0223bec70d670d29a30d9bcee197910e37cf2a10f0dc3c5ac44d865aec0d7052fb");
         let correct_answer1 = String::from("HPQdaCWR3E4rvWYj8DnixfZ1pyYrMT7rEc");
-        assert_eq!(derive_ver1_address(pubkey1), correct_answer1);
+        assert_eq!(
+            derive_ver1_address(pubkey1, &network_params),
+            correct_answer1
+        );
 
         let pubkey2 =
             String::from("02c4ef70543e18889167ca67c8aa28c1d4c259e89cb34483a8ed6cfd3a03e8246b");
         let correct_answer2 = String::from("HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFK");
-        assert_eq!(derive_ver1_address(pubkey2), correct_answer2);
+        assert_eq!(
+            derive_ver1_address(pubkey2, &network_params),
+            correct_answer2
+        );
+    }
+
+    #[test]
+    pub fn test_should_derive_ver1_address_of_other_network() {
+        // Private chain with its own version byte and checksum XOR seed
+        let network_params = Ver1NetworkParams {
+            pubkey_hash_prefix: 0x41,
+            script_hash_prefix: 0x42,
+            checksum_xor: 0x5052_4956,
+        };
+        let pubkey =
+            String::from("02989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f");
+        let address = derive_ver1_address(pubkey, &network_params);
+        assert_eq!(address, "TQuBAuCxUyqN8kJ9DxuK2PpVUNZde8Ky61");
+
+        assert_eq!(validate_ver1_address(&address, &network_params), true);
+        assert_eq!(
+            validate_ver1_address(&address, &Ver1NetworkParams::default()),
+            false
+        );
+        assert_eq!(
+            validate_ver1_address("HMT6ZBkkjUFTguoycUaLuG1pjm83s8dfi2", &network_params),
+            false
+        );
+    }
+
+    #[test]
+    pub fn test_should_validate_ver1_address_format() {
+        let network_params = Ver1NetworkParams::default();
+        assert_eq!(
+            validate_ver1_address("HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFK", &network_params),
+            true
+        );
+        assert_eq!(
+            validate_ver1_address("HPQdaCWR3E4rvWYj8DnixfZ1pyYrMT7rEc", &network_params),
+            true
+        );
+
+        // Truncated, and not a base58 string
+        assert_eq!(
+            validate_ver1_address("HLkXSESzSaDZgU25CQrmxkjRayKfs5x", &network_params),
+            false
+        );
+        assert_eq!(
+            validate_ver1_address("HLkXSESzSaDZgU25CQrmxkjRayKfs5x0O", &network_params),
+            false
+        );
+        // Checksum mismatch by a typo
+        assert_eq!(
+            verify_ver1_address_checksum("HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFK", &network_params),
+            true
+        );
+        assert_eq!(
+            verify_ver1_address_checksum("HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFL", &network_params),
+            false
+        );
+        assert_eq!(
+            verify_ver1_address_checksum("HLkXSESzSaDZgU25CQrmxkjRaykfs5xBFK", &network_params),
+            false
+        );
+    }
+
+    #[test]
+    pub fn test_should_verify_merkle_proof() {
+        let leaf0 = merkle::leaf_hash(0, "HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFK", U512::from(10_000));
+        let leaf1 = merkle::leaf_hash(1, "HPQdaCWR3E4rvWYj8DnixfZ1pyYrMT7rEc", U512::from(20_000));
+        let leaf2 = merkle::leaf_hash(2, "H9EtjvP88K51nTSevyNW2p9VkSbuzhwgWQ", U512::from(30_000));
+        let node01 = merkle::node_hash(&leaf0, &leaf1);
+        let node22 = merkle::node_hash(&leaf2, &leaf2);
+        let root = merkle::node_hash(&node01, &node22);
+
+        assert_eq!(
+            merkle::verify_proof(&root, leaf0, 0, &[leaf1, node22]),
+            true
+        );
+        assert_eq!(
+            merkle::verify_proof(&root, leaf1, 1, &[leaf0, node22]),
+            true
+        );
+        assert_eq!(
+            merkle::verify_proof(&root, leaf2, 2, &[leaf2, node01]),
+            true
+        );
+
+        // Wrong position, tampered balance and out of range index
+        assert_eq!(
+            merkle::verify_proof(&root, leaf1, 0, &[leaf0, node22]),
+            false
+        );
+        let tampered =
+            merkle::leaf_hash(1, "HPQdaCWR3E4rvWYj8DnixfZ1pyYrMT7rEc", U512::from(20_001));
+        assert_eq!(
+            merkle::verify_proof(&root, tampered, 1, &[leaf0, node22]),
+            false
+        );
+        assert_eq!(
+            merkle::verify_proof(&root, leaf0, 4, &[leaf1, node22]),
+            false
+        );
+
+        // A proof deeper than 64 levels is rejected even if its hashes match
+        let long_proof = [leaf1; 65];
+        let long_root = long_proof.iter().fold(leaf0, |computed, sibling| {
+            merkle::node_hash(&computed, sibling)
+        });
+        assert_eq!(
+            merkle::verify_proof(&long_root, leaf0, 0, &long_proof),
+            false
+        );
+    }
+
+    #[test]
+    pub fn test_should_verify_multisig() {
+        let network_params = Ver1NetworkParams::default();
+        // 2-of-3 multisig
+        let redeem_script = hex::decode(
+            "522102989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f2103f991f944\
+             d1e1954a7fc8b9bf62e0d78f015f4c07762d505e20e6c45260a3661b210256b328b30c8bf5839e2405\
+             8747879408bdb36241dc9c2e7c619faa12b292096753ae",
+        )
+        .unwrap();
+        let (required_signatures, ver1_pubkeys) = parse_multisig_redeem_script(&redeem_script);
+        assert_eq!(required_signatures, 2);
+        assert_eq!(ver1_pubkeys.len(), 3);
+
+        let address = derive_ver1_p2sh_address(&redeem_script, &network_params);
+        assert_eq!(address, "4HN7gZ4qWp2GHKikgEvHC8MtXJWvDnrRV6");
+
+        let message = swap_message([0x11u8; 32], &"02".repeat(32), &address);
+        let signature_1st = String::from(
+            "51dad23bf8e8b2b1da745ec0c6d669b2f2aa97b98daaa1142279433bbcef371b663907ce28a52d93\
+             d1f7ec21146f04142c1cef97052df9405eca852010764485",
+        );
+        let signature_3rd = String::from(
+            "87eb8072e81da51ef8e32e9f147f442df662a12d6c1a5c9d73c04a0c37055e8508ca0a28ff8f246d\
+             330ec6f070ae1bbb6c666135baf736adb9e51d5a8918e51f",
+        );
+
+        assert_eq!(
+            multisig_verification(
+                &ver1_pubkeys,
+                required_signatures,
+                swap_message_digest(&message),
+                vec![signature_1st.clone(), signature_3rd.clone()]
+            ),
+            true
+        );
+
+        // Not enough signatures
+        assert_eq!(
+            multisig_verification(
+                &ver1_pubkeys,
+                required_signatures,
+                swap_message_digest(&message),
+                vec![signature_1st.clone()]
+            ),
+            false
+        );
+
+        // Signatures should be in the order of the public keys
+        assert_eq!(
+            multisig_verification(
+                &ver1_pubkeys,
+                required_signatures,
+                swap_message_digest(&message),
+                vec![signature_3rd, signature_1st]
+            ),
+            false
+        );
+    }
+
+    #[test]
+    pub fn test_should_allocate_partial_claim_in_order() {
+        let amounts = vec![U512::from(3_000), U512::from(4_000), U512::from(2_000)];
+
+        assert_eq!(
+            allocate_partial_claim(U512::from(5_000), &amounts),
+            vec![U512::from(3_000), U512::from(2_000), U512::from(0)]
+        );
+        assert_eq!(
+            allocate_partial_claim(U512::from(0), &amounts),
+            vec![U512::from(0), U512::from(0), U512::from(0)]
+        );
+        assert_eq!(
+            allocate_partial_claim(U512::from(10_000), &amounts),
+            amounts
+        );
+    }
+
+    #[test]
+    pub fn test_should_organize_kyc_tier_cap() {
+        let zero_cap_tier = UnitKYCTierData {
+            cap: Some(U512::from(0)),
+            period_limit: U512::from(0),
+            period: 0,
+            enabled: true,
+        };
+        let no_cap_tier = UnitKYCTierData {
+            cap: None,
+            ..zero_cap_tier.clone()
+        };
+
+        // A cap of 0 refuses every claim, while a tier without cap has no key for it
+        assert_eq!(
+            zero_cap_tier.organize().get("cap"),
+            Some(&String::from("0"))
+        );
+        assert_eq!(no_cap_tier.organize().get("cap"), None);
+
+        assert_eq!(
+            UnitKYCTierData::restore(zero_cap_tier.organize()),
+            zero_cap_tier
+        );
+        assert_eq!(
+            UnitKYCTierData::restore(no_cap_tier.organize()),
+            no_cap_tier
+        );
     }
 }
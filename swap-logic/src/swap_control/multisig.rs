@@ -0,0 +1,426 @@
+use super::{
+    amend_snapshot, apply_claim_window, check_admin, check_reserve,
+    error::Error as SwapError,
+    events,
+    events::SwapEvent,
+    kyc_tier, remove_denylist_entry, remove_granted_role, replace_merkle_root, swap_storage,
+    swap_storage::{UnitKYCTierData, UnitProposalData},
+    sweep_contract_purse, validate_role,
+};
+use crate::constants::actions;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use contract::contract_api::{runtime, system};
+use num_traits::Num;
+use types::{account::PublicKey, Key, TransferResult, U512};
+
+const PROPOSAL_STATUS_PENDING: &str = "pending";
+const PROPOSAL_STATUS_EXECUTED: &str = "executed";
+const PROPOSAL_STATUS_CANCELLED: &str = "cancelled";
+
+enum ProposalAction {
+    InsertKYCAllowanceCap(U512),
+    AmendSnapshotRecord(String, U512, PublicKey),
+    TransferPurseFunds(PublicKey, U512),
+    SetMultisigConfig(Vec<PublicKey>, U512),
+    SweepUnclaimed(PublicKey),
+    SetKYCTier(U512, UnitKYCTierData),
+    ProposeAdmin(PublicKey),
+    GrantRole(String, PublicKey),
+    RevokeRole(String, PublicKey),
+    UnpauseSwap,
+    SetClaimWindow(u64, u64),
+    RemoveFromDenylist(String),
+    ReplaceSnapshotMerkleRoot(String, U512, PublicKey),
+}
+
+// Signers are set by the admin only for the first time.
+// After that, they are changed by a proposal of set_multisig_config action.
+pub fn set_multisig_config(signers: Vec<PublicKey>, threshold: U512) {
+    check_admin();
+
+    if swap_storage::check_multisig_config_existence() {
+        runtime::revert(SwapError::MultisigRequired);
+    }
+
+    apply_multisig_config(signers, threshold);
+
+    // A pending admin proposed by the single key should not take over after this
+    if swap_storage::check_pending_admin_existence() {
+        swap_storage::remove_pending_admin();
+    }
+}
+
+// Admin methods are closed for direct calls, once the signers are configured.
+// They are executed by proposals instead, so that the admin key alone
+// cannot hand the admin or a role to itself, nor change the claim conditions.
+pub fn check_multisig_not_configured() {
+    if swap_storage::check_multisig_config_existence() {
+        runtime::revert(SwapError::MultisigRequired);
+    }
+}
+
+// Returns ID of the new proposal.
+// Arguments are validated here, so that a malformed proposal never gets approvals.
+pub fn propose_action(action: String, args: Vec<String>) -> U512 {
+    let curr_account = runtime::get_caller();
+    if curr_account != swap_storage::load_admin() {
+        check_multisig_signer(curr_account);
+    } else if !swap_storage::check_multisig_config_existence() {
+        runtime::revert(SwapError::MultisigNotConfigured);
+    }
+
+    parse_action(&action, &args);
+
+    let proposal_id = swap_storage::load_proposal_count() + U512::from(1);
+    swap_storage::save_proposal_count(proposal_id);
+
+    let new_data = UnitProposalData {
+        action,
+        args,
+        proposer: swap_storage::to_hex_string(curr_account),
+        status: String::from(PROPOSAL_STATUS_PENDING),
+    };
+    swap_storage::save_proposal_data(proposal_id, &new_data);
+
+    let mut pending_proposals = swap_storage::load_pending_proposals();
+    pending_proposals.push(proposal_id);
+    swap_storage::save_pending_proposals(pending_proposals);
+
+    proposal_id
+}
+
+pub fn approve_proposal(proposal_id: U512) {
+    let curr_account = runtime::get_caller();
+    check_multisig_signer(curr_account);
+    load_pending_proposal(proposal_id);
+
+    if swap_storage::check_proposal_approval(proposal_id, curr_account) {
+        runtime::revert(SwapError::AlreadyApprovedProposal);
+    }
+    swap_storage::save_proposal_approval(proposal_id, curr_account);
+}
+
+// Returns the amount which the caller should fund to the contract purse.
+// It is non-zero only when an amendment or a replaced merkle root increases the snapshot balance.
+pub fn execute_proposal(proposal_id: U512) -> U512 {
+    let curr_account = runtime::get_caller();
+    check_multisig_signer(curr_account);
+    let mut data = load_pending_proposal(proposal_id);
+
+    // Approvals are counted with the current signers,
+    // so that approvals of removed signers are not valid anymore.
+    if count_approvals(proposal_id) < swap_storage::load_multisig_threshold() {
+        runtime::revert(SwapError::NotEnoughApprovals);
+    }
+
+    data.status = String::from(PROPOSAL_STATUS_EXECUTED);
+    swap_storage::save_proposal_data(proposal_id, &data);
+    remove_pending_proposal(proposal_id);
+
+    match parse_action(&data.action, &data.args) {
+        ProposalAction::InsertKYCAllowanceCap(allowance_cap) => {
+            swap_storage::save_kyc_border_allowance_cap(allowance_cap);
+            events::emit(SwapEvent::CapChanged { allowance_cap });
+            U512::from(0)
+        }
+        ProposalAction::AmendSnapshotRecord(ver1_address, new_balance, refund_recipient) => {
+            let prev_balance = amend_snapshot(ver1_address, new_balance, refund_recipient);
+            if new_balance > prev_balance {
+                new_balance - prev_balance
+            } else {
+                U512::from(0)
+            }
+        }
+        ProposalAction::TransferPurseFunds(recipient, amount) => {
+            // Only the surplus over the outstanding liabilities can be moved
+            check_reserve(amount, U512::from(0));
+
+            let contract_purse = swap_storage::load_contract_wallet();
+            let transfer_res: TransferResult =
+                system::transfer_from_purse_to_account(contract_purse, recipient, amount);
+
+            if let Err(err) = transfer_res {
+                runtime::revert(err);
+            }
+            U512::from(0)
+        }
+        ProposalAction::SetMultisigConfig(signers, threshold) => {
+            apply_multisig_config(signers, threshold);
+            U512::from(0)
+        }
+        ProposalAction::SweepUnclaimed(treasury) => {
+            sweep_contract_purse(Key::Account(treasury));
+            U512::from(0)
+        }
+        ProposalAction::SetKYCTier(kyc_level, tier) => {
+            kyc_tier::apply_kyc_tier(kyc_level, tier);
+            U512::from(0)
+        }
+        ProposalAction::ProposeAdmin(new_admin) => {
+            swap_storage::save_pending_admin(new_admin);
+            U512::from(0)
+        }
+        ProposalAction::GrantRole(role, account) => {
+            swap_storage::save_role(&role, account);
+            U512::from(0)
+        }
+        ProposalAction::RevokeRole(role, account) => {
+            remove_granted_role(&role, account);
+            U512::from(0)
+        }
+        ProposalAction::UnpauseSwap => {
+            swap_storage::save_paused(false);
+            U512::from(0)
+        }
+        ProposalAction::SetClaimWindow(claim_start, claim_end) => {
+            apply_claim_window(claim_start, claim_end);
+            U512::from(0)
+        }
+        ProposalAction::RemoveFromDenylist(ver1_address) => {
+            remove_denylist_entry(ver1_address);
+            U512::from(0)
+        }
+        ProposalAction::ReplaceSnapshotMerkleRoot(merkle_root, total_amount, refund_recipient) => {
+            let prev_total = replace_merkle_root(merkle_root, total_amount, refund_recipient);
+            if total_amount > prev_total {
+                total_amount - prev_total
+            } else {
+                U512::from(0)
+            }
+        }
+    }
+}
+
+// Only the proposer drops a proposal which is not executed yet,
+// so that the admin key alone cannot block an action which the signers approve.
+pub fn cancel_proposal(proposal_id: U512) {
+    let mut data = load_pending_proposal(proposal_id);
+
+    let curr_account = runtime::get_caller();
+    if swap_storage::to_hex_string(curr_account) != data.proposer {
+        runtime::revert(SwapError::NotProposer);
+    }
+
+    data.status = String::from(PROPOSAL_STATUS_CANCELLED);
+    swap_storage::save_proposal_data(proposal_id, &data);
+    remove_pending_proposal(proposal_id);
+}
+
+pub fn get_proposal(proposal_id: U512) -> BTreeMap<String, String> {
+    if !swap_storage::check_proposal_data_existence(proposal_id) {
+        runtime::revert(SwapError::ProposalNotFound);
+    }
+
+    let mut res = swap_storage::load_proposal_data(proposal_id).organize();
+    res.insert(
+        String::from("num_of_approvals"),
+        count_approvals(proposal_id).to_string(),
+    );
+    res
+}
+
+pub fn get_pending_proposals() -> Vec<U512> {
+    swap_storage::load_pending_proposals()
+}
+
+fn apply_multisig_config(signers: Vec<PublicKey>, threshold: U512) {
+    let distinct_signers: BTreeSet<String> = signers
+        .iter()
+        .map(|signer| swap_storage::to_hex_string(*signer))
+        .collect();
+
+    if threshold == U512::from(0)
+        || threshold > U512::from(signers.len())
+        || distinct_signers.len() != signers.len()
+    {
+        runtime::revert(SwapError::InvalidMultisigConfig);
+    }
+
+    swap_storage::save_multisig_config(signers, threshold);
+}
+
+// Signers can take an emergency action alone, without a proposal
+pub fn is_multisig_signer(account: PublicKey) -> bool {
+    swap_storage::check_multisig_config_existence()
+        && swap_storage::load_multisig_signers().contains(&account)
+}
+
+fn check_multisig_signer(account: PublicKey) {
+    if !swap_storage::check_multisig_config_existence() {
+        runtime::revert(SwapError::MultisigNotConfigured);
+    }
+    if !swap_storage::load_multisig_signers().contains(&account) {
+        runtime::revert(SwapError::NotMultisigSigner);
+    }
+}
+
+fn load_pending_proposal(proposal_id: U512) -> UnitProposalData {
+    if !swap_storage::check_proposal_data_existence(proposal_id) {
+        runtime::revert(SwapError::ProposalNotFound);
+    }
+
+    let data = swap_storage::load_proposal_data(proposal_id);
+    if data.status != PROPOSAL_STATUS_PENDING {
+        runtime::revert(SwapError::ProposalNotPending);
+    }
+    data
+}
+
+fn remove_pending_proposal(proposal_id: U512) {
+    let mut pending_proposals = swap_storage::load_pending_proposals();
+    pending_proposals.retain(|id| *id != proposal_id);
+    swap_storage::save_pending_proposals(pending_proposals);
+}
+
+fn count_approvals(proposal_id: U512) -> U512 {
+    if !swap_storage::check_multisig_config_existence() {
+        return U512::from(0);
+    }
+
+    let num_of_approvals = swap_storage::load_multisig_signers()
+        .into_iter()
+        .filter(|signer| swap_storage::check_proposal_approval(proposal_id, *signer))
+        .count();
+    U512::from(num_of_approvals)
+}
+
+fn parse_action(action: &str, args: &[String]) -> ProposalAction {
+    match action {
+        actions::ACTION_INSERT_KYC_ALLOWANCE_CAP => {
+            if args.len() != 1 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::InsertKYCAllowanceCap(parse_amount(&args[0]))
+        }
+        actions::ACTION_AMEND_SNAPSHOT_RECORD => {
+            if args.len() != 3 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::AmendSnapshotRecord(
+                args[0].clone(),
+                parse_amount(&args[1]),
+                parse_public_key(&args[2]),
+            )
+        }
+        actions::ACTION_TRANSFER_PURSE_FUNDS => {
+            if args.len() != 2 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::TransferPurseFunds(parse_public_key(&args[0]), parse_amount(&args[1]))
+        }
+        actions::ACTION_SET_MULTISIG_CONFIG => {
+            if args.len() < 2 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            let signers = args[1..].iter().map(|arg| parse_public_key(arg)).collect();
+            ProposalAction::SetMultisigConfig(signers, parse_amount(&args[0]))
+        }
+        actions::ACTION_SWEEP_UNCLAIMED => {
+            if args.len() != 1 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::SweepUnclaimed(parse_public_key(&args[0]))
+        }
+        actions::ACTION_SET_KYC_TIER => {
+            if args.len() != 5 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            let period = parse_u64(&args[3]);
+            let enabled: bool = match args[4].parse() {
+                Ok(val) => val,
+                Err(_) => runtime::revert(SwapError::InvalidProposalArgs),
+            };
+            ProposalAction::SetKYCTier(
+                parse_amount(&args[0]),
+                UnitKYCTierData::new(
+                    parse_amount(&args[1]),
+                    parse_amount(&args[2]),
+                    period,
+                    enabled,
+                ),
+            )
+        }
+        actions::ACTION_PROPOSE_ADMIN => {
+            if args.len() != 1 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::ProposeAdmin(parse_public_key(&args[0]))
+        }
+        actions::ACTION_GRANT_ROLE => {
+            if args.len() != 2 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            validate_role(&args[0]);
+            ProposalAction::GrantRole(args[0].clone(), parse_public_key(&args[1]))
+        }
+        actions::ACTION_REVOKE_ROLE => {
+            if args.len() != 2 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            validate_role(&args[0]);
+            ProposalAction::RevokeRole(args[0].clone(), parse_public_key(&args[1]))
+        }
+        actions::ACTION_UNPAUSE_SWAP => {
+            if !args.is_empty() {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::UnpauseSwap
+        }
+        actions::ACTION_SET_CLAIM_WINDOW => {
+            if args.len() != 2 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::SetClaimWindow(parse_u64(&args[0]), parse_u64(&args[1]))
+        }
+        actions::ACTION_REMOVE_FROM_DENYLIST => {
+            if args.len() != 1 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::RemoveFromDenylist(args[0].clone())
+        }
+        actions::ACTION_REPLACE_SNAPSHOT_MERKLE_ROOT => {
+            if args.len() != 3 {
+                runtime::revert(SwapError::InvalidProposalArgs);
+            }
+            ProposalAction::ReplaceSnapshotMerkleRoot(
+                args[0].clone(),
+                parse_amount(&args[1]),
+                parse_public_key(&args[2]),
+            )
+        }
+        _ => runtime::revert(SwapError::InvalidProposalAction),
+    }
+}
+
+fn parse_amount(arg: &str) -> U512 {
+    match U512::from_str_radix(arg, 10) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::InvalidProposalArgs),
+    }
+}
+
+fn parse_u64(arg: &str) -> u64 {
+    match arg.parse() {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::InvalidProposalArgs),
+    }
+}
+
+// Only ed25519 public keys, which are 32 bytes, are accepted
+fn parse_public_key(arg: &str) -> PublicKey {
+    let bytes = match hex::decode(arg) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::InvalidProposalArgs),
+    };
+    if bytes.len() != 32 {
+        runtime::revert(SwapError::InvalidProposalArgs);
+    }
+
+    let mut public_key_bytes: [u8; 32] = [0u8; 32];
+    public_key_bytes.copy_from_slice(&bytes);
+    PublicKey::ed25519_from(public_key_bytes)
+}
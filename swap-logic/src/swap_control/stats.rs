@@ -0,0 +1,138 @@
+use super::swap_storage;
+use crate::constants::keys;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+};
+use num_traits::Num;
+use types::U512;
+
+// Aggregated counters for dashboards, kept in a single map of decimal strings.
+// Merkle snapshot leaves are not known on chain, so the merkle snapshot is counted
+// by its total amount, and its leaves only when claimed.
+
+pub fn count_snapshot_records(num_of_records: usize) {
+    let mut stats = swap_storage::load_swap_stats();
+    increase(
+        &mut stats,
+        keys::KEY_STATS_NUM_OF_SNAPSHOT_RECORDS,
+        U512::from(num_of_records),
+    );
+    swap_storage::save_swap_stats(stats);
+}
+
+// Replacing the root overwrites the amount
+pub fn count_merkle_snapshot(total_amount: U512) {
+    let mut stats = swap_storage::load_swap_stats();
+    stats.insert(
+        keys::KEY_STATS_MERKLE_SNAPSHOT_AMOUNT.to_string(),
+        total_amount.to_string(),
+    );
+    swap_storage::save_swap_stats(stats);
+}
+
+pub fn count_claimed_merkle_leaves(num_of_leaves: usize) {
+    let mut stats = swap_storage::load_swap_stats();
+    increase(
+        &mut stats,
+        keys::KEY_STATS_NUM_OF_CLAIMED_MERKLE_LEAVES,
+        U512::from(num_of_leaves),
+    );
+    swap_storage::save_swap_stats(stats);
+}
+
+pub fn count_kyc_registration(kyc_level: U512) {
+    let mut stats = swap_storage::load_swap_stats();
+    increase(&mut stats, &kyc_registrations_key(kyc_level), U512::from(1));
+    swap_storage::save_swap_stats(stats);
+}
+
+pub fn count_kyc_level_change(prev_level: U512, new_level: U512) {
+    let mut stats = swap_storage::load_swap_stats();
+    decrease(
+        &mut stats,
+        &kyc_registrations_key(prev_level),
+        U512::from(1),
+    );
+    increase(&mut stats, &kyc_registrations_key(new_level), U512::from(1));
+    swap_storage::save_swap_stats(stats);
+}
+
+pub fn count_claim(
+    kyc_level: U512,
+    num_of_records: usize,
+    swapped_amount: U512,
+    is_first_claim: bool,
+) {
+    let mut stats = swap_storage::load_swap_stats();
+    increase(
+        &mut stats,
+        keys::KEY_STATS_NUM_OF_CLAIMED_RECORDS,
+        U512::from(num_of_records),
+    );
+    increase(
+        &mut stats,
+        &kyc_swapped_amount_key(kyc_level),
+        swapped_amount,
+    );
+    if is_first_claim {
+        increase(
+            &mut stats,
+            keys::KEY_STATS_NUM_OF_CLAIMING_ACCOUNTS,
+            U512::from(1),
+        );
+    }
+    swap_storage::save_swap_stats(stats);
+}
+
+pub fn get_stats() -> BTreeMap<String, String> {
+    let mut stats = swap_storage::load_swap_stats();
+    for key in &[
+        keys::KEY_STATS_NUM_OF_SNAPSHOT_RECORDS,
+        keys::KEY_STATS_NUM_OF_CLAIMED_RECORDS,
+        keys::KEY_STATS_NUM_OF_CLAIMING_ACCOUNTS,
+        keys::KEY_STATS_MERKLE_SNAPSHOT_AMOUNT,
+        keys::KEY_STATS_NUM_OF_CLAIMED_MERKLE_LEAVES,
+    ] {
+        stats
+            .entry(key.to_string())
+            .or_insert_with(|| String::from("0"));
+    }
+    stats
+}
+
+fn kyc_registrations_key(kyc_level: U512) -> String {
+    format!(
+        "{}{}{}",
+        keys::KEY_STATS_KYC_LEVEL_PREFIX,
+        kyc_level,
+        keys::KEY_STATS_REGISTRATIONS_SUFFIX
+    )
+}
+
+fn kyc_swapped_amount_key(kyc_level: U512) -> String {
+    format!(
+        "{}{}{}",
+        keys::KEY_STATS_KYC_LEVEL_PREFIX,
+        kyc_level,
+        keys::KEY_STATS_SWAPPED_AMOUNT_SUFFIX
+    )
+}
+
+fn load_counter(stats: &BTreeMap<String, String>, key: &str) -> U512 {
+    match stats.get(key) {
+        Some(val) => U512::from_str_radix(val, 10).unwrap_or_default(),
+        None => U512::from(0),
+    }
+}
+
+fn increase(stats: &mut BTreeMap<String, String>, key: &str, amount: U512) {
+    let counter = load_counter(stats, key) + amount;
+    stats.insert(key.to_string(), counter.to_string());
+}
+
+fn decrease(stats: &mut BTreeMap<String, String>, key: &str, amount: U512) {
+    let counter = load_counter(stats, key).saturating_sub(amount);
+    stats.insert(key.to_string(), counter.to_string());
+}
@@ -1,7 +1,9 @@
 use crate::constants::{keys, users};
 use alloc::{
     collections::BTreeMap,
+    format,
     string::{String, ToString},
+    vec::Vec,
 };
 use contract::{
     contract_api::{runtime, storage},
@@ -9,18 +11,62 @@ use contract::{
 };
 use core::{convert::TryInto, fmt::Write};
 use num_traits::Num;
-use types::{account::PublicKey, ApiError, URef, U512};
+use types::{
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    ApiError, CLTyped, Key, URef, U512,
+};
+
+use super::ver1::Ver1NetworkParams;
+
+const MERKLE_CLAIMED_BITMAP_WORD_BITS: u64 = 512;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UnitSnapshotData {
     pub prev_balance: U512,
     pub is_swapped: U512,
+    // Unpaid part of a partial claim, which is paid after KYC upgrade
+    pub pending_amount: U512,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UnitKYCData {
     pub kyc_level: U512,
     pub swapped_amount: U512,
+    // Usage of the per-period limit of the tier, in the period started at period_start
+    pub period_start: u64,
+    pub period_swapped_amount: U512,
+    // Block time when the approval expires. 0 for an approval without expiry.
+    pub expires_at: u64,
+    pub status: KYCStatus,
+}
+
+// Frozen accounts can be unfrozen, but revocation is final.
+// Records are kept in every status, for auditing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KYCStatus {
+    Active,
+    Frozen,
+    Revoked,
+}
+
+impl KYCStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KYCStatus::Active => "active",
+            KYCStatus::Frozen => "frozen",
+            KYCStatus::Revoked => "revoked",
+        }
+    }
+
+    // Records written before the status have no status, and are active
+    fn restore(status: Option<&String>) -> Self {
+        match status.map(|val| val.as_str()) {
+            Some("frozen") => KYCStatus::Frozen,
+            Some("revoked") => KYCStatus::Revoked,
+            _ => KYCStatus::Active,
+        }
+    }
 }
 
 impl UnitSnapshotData {
@@ -33,10 +79,15 @@ impl UnitSnapshotData {
         let is_swapped =
             U512::from_str_radix(unit_tree.get(keys::KEY_IS_SWAPPED).unwrap_or_revert(), 10)
                 .unwrap_or_default();
+        let pending_amount = match unit_tree.get(keys::KEY_PENDING_AMOUNT) {
+            Some(val) => U512::from_str_radix(val, 10).unwrap_or_default(),
+            None => U512::from(0),
+        };
 
         UnitSnapshotData {
             prev_balance,
             is_swapped,
+            pending_amount,
         }
     }
 
@@ -54,6 +105,10 @@ impl UnitSnapshotData {
 
         res.insert(keys::KEY_PREV_BALANCE_KEY.to_string(), prev_balance);
         res.insert(keys::KEY_IS_SWAPPED.to_string(), is_swapped);
+        res.insert(
+            keys::KEY_PENDING_AMOUNT.to_string(),
+            self.pending_amount.to_string(),
+        );
 
         res
     }
@@ -71,9 +126,29 @@ impl UnitKYCData {
         )
         .unwrap_or_default();
 
+        let period_start: u64 = match unit_tree.get(keys::KEY_PERIOD_START) {
+            Some(val) => val.parse().unwrap_or_default(),
+            None => 0,
+        };
+        let period_swapped_amount = match unit_tree.get(keys::KEY_PERIOD_SWAPPED_AMOUNT) {
+            Some(val) => U512::from_str_radix(val, 10).unwrap_or_default(),
+            None => U512::from(0),
+        };
+
+        let expires_at: u64 = match unit_tree.get(keys::KEY_EXPIRES_AT) {
+            Some(val) => val.parse().unwrap_or_default(),
+            None => 0,
+        };
+
+        let status = KYCStatus::restore(unit_tree.get(keys::KEY_KYC_STATUS));
+
         UnitKYCData {
             kyc_level,
             swapped_amount,
+            period_start,
+            period_swapped_amount,
+            expires_at,
+            status,
         }
     }
 
@@ -91,6 +166,230 @@ impl UnitKYCData {
         let mut res: BTreeMap<String, String> = BTreeMap::new();
         res.insert(keys::KEY_KYC_LEVEL.to_string(), kyc_level);
         res.insert(keys::KEY_SWAPPED_AMOUNT.to_string(), swapped_amount);
+        res.insert(
+            keys::KEY_PERIOD_START.to_string(),
+            self.period_start.to_string(),
+        );
+        res.insert(
+            keys::KEY_PERIOD_SWAPPED_AMOUNT.to_string(),
+            self.period_swapped_amount.to_string(),
+        );
+        res.insert(
+            keys::KEY_EXPIRES_AT.to_string(),
+            self.expires_at.to_string(),
+        );
+        res.insert(
+            keys::KEY_KYC_STATUS.to_string(),
+            self.status.as_str().to_string(),
+        );
+
+        res
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitKYCTierData {
+    // None if the tier has no cap. The cap key is omitted then.
+    pub cap: Option<U512>,
+    // Both are 0 if the tier has no per-period limit. Period is in milliseconds.
+    pub period_limit: U512,
+    pub period: u64,
+    pub enabled: bool,
+}
+
+impl UnitKYCTierData {
+    // Cap 0 is given for a tier without cap
+    pub fn new(cap: U512, period_limit: U512, period: u64, enabled: bool) -> Self {
+        UnitKYCTierData {
+            cap: if cap == U512::from(0) {
+                None
+            } else {
+                Some(cap)
+            },
+            period_limit,
+            period,
+            enabled,
+        }
+    }
+
+    pub fn restore(unit_tree: BTreeMap<String, String>) -> Self {
+        let cap = unit_tree
+            .get(keys::KEY_TIER_CAP)
+            .map(|cap| U512::from_str_radix(cap, 10).unwrap_or_default());
+        let period_limit = U512::from_str_radix(
+            unit_tree
+                .get(keys::KEY_TIER_PERIOD_LIMIT)
+                .unwrap_or_revert(),
+            10,
+        )
+        .unwrap_or_default();
+        let period: u64 = unit_tree
+            .get(keys::KEY_TIER_PERIOD)
+            .unwrap_or_revert()
+            .parse()
+            .unwrap_or_default();
+        let enabled = unit_tree.get(keys::KEY_TIER_ENABLED).unwrap_or_revert() == "true";
+
+        UnitKYCTierData {
+            cap,
+            period_limit,
+            period,
+            enabled,
+        }
+    }
+
+    // A cap of 0 is kept, so that it is not read as a tier without cap
+    pub fn organize(&self) -> BTreeMap<String, String> {
+        let mut res: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(cap) = self.cap {
+            res.insert(keys::KEY_TIER_CAP.to_string(), cap.to_string());
+        }
+        res.insert(
+            keys::KEY_TIER_PERIOD_LIMIT.to_string(),
+            self.period_limit.to_string(),
+        );
+        res.insert(keys::KEY_TIER_PERIOD.to_string(), self.period.to_string());
+        res.insert(keys::KEY_TIER_ENABLED.to_string(), self.enabled.to_string());
+
+        res
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitProposalData {
+    pub action: String,
+    pub args: Vec<String>,
+    pub proposer: String,
+    pub status: String,
+}
+
+impl UnitProposalData {
+    pub fn restore(unit_tree: BTreeMap<String, String>) -> Self {
+        let action = unit_tree
+            .get(keys::KEY_PROPOSAL_ACTION)
+            .unwrap_or_revert()
+            .clone();
+        let proposer = unit_tree
+            .get(keys::KEY_PROPOSAL_PROPOSER)
+            .unwrap_or_revert()
+            .clone();
+        let status = unit_tree
+            .get(keys::KEY_PROPOSAL_STATUS)
+            .unwrap_or_revert()
+            .clone();
+
+        let num_of_args: usize = unit_tree
+            .get(keys::KEY_PROPOSAL_NUM_OF_ARGS)
+            .unwrap_or_revert()
+            .parse()
+            .unwrap_or_default();
+        let args = (0..num_of_args)
+            .map(|idx| {
+                unit_tree
+                    .get(&format!("{}{}", keys::KEY_PROPOSAL_ARG_PREFIX, idx))
+                    .unwrap_or_revert()
+                    .clone()
+            })
+            .collect();
+
+        UnitProposalData {
+            action,
+            args,
+            proposer,
+            status,
+        }
+    }
+
+    pub fn organize(&self) -> BTreeMap<String, String> {
+        let mut res: BTreeMap<String, String> = BTreeMap::new();
+        res.insert(keys::KEY_PROPOSAL_ACTION.to_string(), self.action.clone());
+        res.insert(
+            keys::KEY_PROPOSAL_PROPOSER.to_string(),
+            self.proposer.clone(),
+        );
+        res.insert(keys::KEY_PROPOSAL_STATUS.to_string(), self.status.clone());
+        res.insert(
+            keys::KEY_PROPOSAL_NUM_OF_ARGS.to_string(),
+            self.args.len().to_string(),
+        );
+        for (idx, arg) in self.args.iter().enumerate() {
+            res.insert(
+                format!("{}{}", keys::KEY_PROPOSAL_ARG_PREFIX, idx),
+                arg.clone(),
+            );
+        }
+
+        res
+    }
+}
+
+// The deploy hash of the claim is not in the receipt. The runtime does not expose it to contracts,
+// and the caller cannot pass it as an argument either, as the hash covers the arguments of the deploy.
+// The deploy is found by the mainnet account and the block time instead.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitReceiptData {
+    pub receipt_id: U512,
+    pub ver1_address: String,
+    pub mainnet_address: String,
+    pub amount: U512,
+    pub block_time: u64,
+}
+
+impl UnitReceiptData {
+    pub fn restore(unit_tree: BTreeMap<String, String>) -> Self {
+        let receipt_id =
+            U512::from_str_radix(unit_tree.get(keys::KEY_RECEIPT_ID).unwrap_or_revert(), 10)
+                .unwrap_or_default();
+        let ver1_address = unit_tree
+            .get(keys::KEY_RECEIPT_VER1_ADDRESS)
+            .unwrap_or_revert()
+            .clone();
+        let mainnet_address = unit_tree
+            .get(keys::KEY_RECEIPT_MAINNET_ADDRESS)
+            .unwrap_or_revert()
+            .clone();
+        let amount = U512::from_str_radix(
+            unit_tree.get(keys::KEY_RECEIPT_AMOUNT).unwrap_or_revert(),
+            10,
+        )
+        .unwrap_or_default();
+        let block_time: u64 = unit_tree
+            .get(keys::KEY_RECEIPT_BLOCK_TIME)
+            .unwrap_or_revert()
+            .parse()
+            .unwrap_or_default();
+
+        UnitReceiptData {
+            receipt_id,
+            ver1_address,
+            mainnet_address,
+            amount,
+            block_time,
+        }
+    }
+
+    pub fn organize(&self) -> BTreeMap<String, String> {
+        let mut res: BTreeMap<String, String> = BTreeMap::new();
+        res.insert(
+            keys::KEY_RECEIPT_ID.to_string(),
+            self.receipt_id.to_string(),
+        );
+        res.insert(
+            keys::KEY_RECEIPT_VER1_ADDRESS.to_string(),
+            self.ver1_address.clone(),
+        );
+        res.insert(
+            keys::KEY_RECEIPT_MAINNET_ADDRESS.to_string(),
+            self.mainnet_address.clone(),
+        );
+        res.insert(
+            keys::KEY_RECEIPT_AMOUNT.to_string(),
+            self.amount.to_string(),
+        );
+        res.insert(
+            keys::KEY_RECEIPT_BLOCK_TIME.to_string(),
+            self.block_time.to_string(),
+        );
 
         res
     }
@@ -103,6 +402,7 @@ pub fn load_snapshot_data(ver1_address: String) -> UnitSnapshotData {
             return UnitSnapshotData {
                 prev_balance: U512::from(0),
                 is_swapped: U512::from(0),
+                pending_amount: U512::from(0),
             };
         }
     };
@@ -123,6 +423,10 @@ pub fn save_snapshot_data(ver1_address: String, unit_data: UnitSnapshotData) {
     runtime::put_key(&ver1_address, new_data_uref.into());
 }
 
+pub fn check_snapshot_data_existence(ver1_address: &str) -> bool {
+    runtime::has_key(ver1_address)
+}
+
 pub fn load_kyc_data(new_address: PublicKey) -> UnitKYCData {
     let str_new_address = to_hex_string(new_address);
     let data_key: URef = runtime::get_key(&str_new_address)
@@ -153,6 +457,35 @@ pub fn check_kyc_data_existence(new_address: PublicKey) -> bool {
     runtime::has_key(&str_new_address)
 }
 
+// Accounts registered for KYC, in order of the registration, to scan the records by pages.
+// Records registered before the index have no expiry, and are indexed when one is set.
+pub fn load_kyc_account_count() -> U512 {
+    load_amount_or_zero(keys::KEY_KYC_ACCOUNT_COUNT)
+}
+
+pub fn load_kyc_account(seq: U512) -> PublicKey {
+    load_value(&format!("{}{}", keys::KEY_KYC_ACCOUNT_PREFIX, seq))
+}
+
+pub fn index_kyc_account(mainnet_address: PublicKey) {
+    let index_key = format!(
+        "{}{}",
+        keys::KEY_KYC_ACCOUNT_INDEX_PREFIX,
+        to_hex_string(mainnet_address)
+    );
+    if runtime::has_key(&index_key) {
+        return;
+    }
+
+    let seq = load_kyc_account_count() + U512::from(1);
+    overwrite_value(
+        &format!("{}{}", keys::KEY_KYC_ACCOUNT_PREFIX, seq),
+        mainnet_address,
+    );
+    overwrite_value(&index_key, seq);
+    overwrite_value(keys::KEY_KYC_ACCOUNT_COUNT, seq);
+}
+
 pub fn to_hex_string(address: PublicKey) -> String {
     let bytes = address.value();
     let mut ret = String::with_capacity(64);
@@ -174,17 +507,73 @@ pub fn load_admin() -> PublicKey {
         .unwrap_or_revert_with(ApiError::ValueNotFound)
 }
 
-pub fn load_kyc_border_allowance_cap() -> U512 {
-    let kyc_border_allowance_uref: URef = runtime::get_key(keys::KEY_KYC_BORDER_ALLOWANCE_CAP)
+pub fn save_admin(new_admin: PublicKey) {
+    if runtime::has_key(users::KEY_ADMIN) {
+        runtime::remove_key(users::KEY_ADMIN);
+    }
+    let new_data_uref = storage::new_uref(new_admin);
+    runtime::put_key(users::KEY_ADMIN, new_data_uref.into());
+}
+
+pub fn check_pending_admin_existence() -> bool {
+    runtime::has_key(users::KEY_PENDING_ADMIN)
+}
+
+pub fn load_pending_admin() -> PublicKey {
+    let pending_admin_uref: URef = runtime::get_key(users::KEY_PENDING_ADMIN)
         .unwrap_or_revert_with(ApiError::GetKey)
         .try_into()
         .unwrap_or_revert();
 
-    storage::read(kyc_border_allowance_uref)
+    storage::read(pending_admin_uref)
         .unwrap_or_revert_with(ApiError::Read)
         .unwrap_or_revert_with(ApiError::ValueNotFound)
 }
 
+pub fn save_pending_admin(pending_admin: PublicKey) {
+    if runtime::has_key(users::KEY_PENDING_ADMIN) {
+        runtime::remove_key(users::KEY_PENDING_ADMIN);
+    }
+    let new_data_uref = storage::new_uref(pending_admin);
+    runtime::put_key(users::KEY_PENDING_ADMIN, new_data_uref.into());
+}
+
+pub fn remove_pending_admin() {
+    runtime::remove_key(users::KEY_PENDING_ADMIN);
+}
+
+// Role registry. An account has the role if the key "role_<role>_<account hex>" exists.
+fn role_key(role: &str, account: PublicKey) -> String {
+    format!(
+        "{}{}_{}",
+        users::KEY_ROLE_PREFIX,
+        role,
+        to_hex_string(account)
+    )
+}
+
+pub fn check_role(role: &str, account: PublicKey) -> bool {
+    runtime::has_key(&role_key(role, account))
+}
+
+pub fn save_role(role: &str, account: PublicKey) {
+    let key = role_key(role, account);
+    if runtime::has_key(&key) {
+        return;
+    }
+    let new_data_uref = storage::new_uref(true);
+    runtime::put_key(&key, new_data_uref.into());
+}
+
+pub fn remove_role(role: &str, account: PublicKey) {
+    runtime::remove_key(&role_key(role, account));
+}
+
+// Zero until the cap is inserted, so that level 1 can be registered but claims nothing
+pub fn load_kyc_border_allowance_cap() -> U512 {
+    load_amount_or_zero(keys::KEY_KYC_BORDER_ALLOWANCE_CAP)
+}
+
 pub fn save_kyc_border_allowance_cap(value: U512) {
     if runtime::has_key(keys::KEY_KYC_BORDER_ALLOWANCE_CAP) {
         runtime::remove_key(keys::KEY_KYC_BORDER_ALLOWANCE_CAP);
@@ -193,9 +582,444 @@ pub fn save_kyc_border_allowance_cap(value: U512) {
     runtime::put_key(keys::KEY_KYC_BORDER_ALLOWANCE_CAP, new_data_uref.into());
 }
 
+pub fn check_kyc_tier_existence(kyc_level: U512) -> bool {
+    runtime::has_key(&kyc_tier_key(kyc_level))
+}
+
+pub fn load_kyc_tier(kyc_level: U512) -> UnitKYCTierData {
+    UnitKYCTierData::restore(load_value(&kyc_tier_key(kyc_level)))
+}
+
+pub fn save_kyc_tier(kyc_level: U512, tier: &UnitKYCTierData) {
+    overwrite_value(&kyc_tier_key(kyc_level), tier.organize());
+}
+
+fn kyc_tier_key(kyc_level: U512) -> String {
+    format!("{}{}", keys::KEY_KYC_TIER_PREFIX, kyc_level)
+}
+
 pub fn load_contract_wallet() -> URef {
     runtime::get_key(keys::KEY_CONTRACT_PURSE)
         .unwrap_or_revert_with(ApiError::GetKey)
         .try_into()
         .unwrap_or_revert()
 }
+
+pub fn load_contract_hash() -> [u8; 32] {
+    let contract_hash_uref: URef = runtime::get_key(keys::KEY_CONTRACT_HASH)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .try_into()
+        .unwrap_or_revert();
+
+    let contract_hash: Key = storage::read(contract_hash_uref)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_revert_with(ApiError::ValueNotFound);
+
+    contract_hash.into_hash().unwrap_or_revert()
+}
+
+pub fn load_ver1_network_params() -> Ver1NetworkParams {
+    Ver1NetworkParams {
+        pubkey_hash_prefix: load_value(keys::KEY_VER1_PUBKEY_HASH_PREFIX),
+        script_hash_prefix: load_value(keys::KEY_VER1_SCRIPT_HASH_PREFIX),
+        checksum_xor: load_value(keys::KEY_VER1_CHECKSUM_XOR),
+    }
+}
+
+pub fn check_snapshot_merkle_root_existence() -> bool {
+    runtime::has_key(keys::KEY_SNAPSHOT_MERKLE_ROOT)
+}
+
+pub fn load_snapshot_merkle_root() -> String {
+    let merkle_root_uref: URef = runtime::get_key(keys::KEY_SNAPSHOT_MERKLE_ROOT)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .try_into()
+        .unwrap_or_revert();
+
+    storage::read(merkle_root_uref)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_revert_with(ApiError::ValueNotFound)
+}
+
+pub fn save_snapshot_merkle_root(merkle_root: String) {
+    overwrite_value(keys::KEY_SNAPSHOT_MERKLE_ROOT, merkle_root);
+}
+
+// Sum of every leaf of the root
+pub fn load_snapshot_merkle_total() -> U512 {
+    load_amount_or_zero(keys::KEY_SNAPSHOT_MERKLE_TOTAL)
+}
+
+pub fn save_snapshot_merkle_total(value: U512) {
+    overwrite_value(keys::KEY_SNAPSHOT_MERKLE_TOTAL, value);
+}
+
+pub fn load_snapshot_merkle_claimed_leaves() -> U512 {
+    load_amount_or_zero(keys::KEY_SNAPSHOT_MERKLE_CLAIMED_LEAVES)
+}
+
+pub fn save_snapshot_merkle_claimed_leaves(value: U512) {
+    overwrite_value(keys::KEY_SNAPSHOT_MERKLE_CLAIMED_LEAVES, value);
+}
+
+// Sum of the balances of claimed leaves
+pub fn load_snapshot_merkle_claimed_amount() -> U512 {
+    load_amount_or_zero(keys::KEY_SNAPSHOT_MERKLE_CLAIMED_AMOUNT)
+}
+
+pub fn save_snapshot_merkle_claimed_amount(value: U512) {
+    overwrite_value(keys::KEY_SNAPSHOT_MERKLE_CLAIMED_AMOUNT, value);
+}
+
+// Balances of the leaves left unclaimed when the purse is swept
+pub fn load_snapshot_merkle_expired() -> U512 {
+    load_amount_or_zero(keys::KEY_SNAPSHOT_MERKLE_EXPIRED)
+}
+
+pub fn save_snapshot_merkle_expired(value: U512) {
+    overwrite_value(keys::KEY_SNAPSHOT_MERKLE_EXPIRED, value);
+}
+
+// Claimed leaves are tracked in 512-bit words, one named key per word.
+pub fn check_merkle_leaf_claimed(leaf_index: u64) -> bool {
+    let word = load_merkle_claimed_word(leaf_index / MERKLE_CLAIMED_BITMAP_WORD_BITS);
+    word.bit((leaf_index % MERKLE_CLAIMED_BITMAP_WORD_BITS) as usize)
+}
+
+pub fn mark_merkle_leaf_claimed(leaf_index: u64) {
+    let word_index = leaf_index / MERKLE_CLAIMED_BITMAP_WORD_BITS;
+    let bit = (leaf_index % MERKLE_CLAIMED_BITMAP_WORD_BITS) as usize;
+
+    let word = load_merkle_claimed_word(word_index) | (U512::one() << bit);
+    save_merkle_claimed_word(word_index, word);
+}
+
+// Ver1 addresses claimed with merkle proofs, so that the per-address record
+// of the same address cannot be claimed again, and vice versa.
+fn merkle_claimed_address_key(ver1_address: &str) -> String {
+    format!(
+        "{}{}",
+        keys::KEY_MERKLE_CLAIMED_ADDRESS_PREFIX,
+        ver1_address
+    )
+}
+
+pub fn check_merkle_address_claimed(ver1_address: &str) -> bool {
+    runtime::has_key(&merkle_claimed_address_key(ver1_address))
+}
+
+pub fn mark_merkle_address_claimed(ver1_address: &str) {
+    let new_data_uref = storage::new_uref(true);
+    runtime::put_key(
+        &merkle_claimed_address_key(ver1_address),
+        new_data_uref.into(),
+    );
+}
+
+fn load_merkle_claimed_word(word_index: u64) -> U512 {
+    let word_key = format!("{}{}", keys::KEY_MERKLE_CLAIMED_BITMAP_PREFIX, word_index);
+    let word_uref: URef = match runtime::get_key(&word_key) {
+        Some(word_uref) => word_uref.try_into().unwrap_or_revert(),
+        None => return U512::from(0),
+    };
+
+    storage::read(word_uref)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_revert_with(ApiError::ValueNotFound)
+}
+
+fn save_merkle_claimed_word(word_index: u64, word: U512) {
+    let word_key = format!("{}{}", keys::KEY_MERKLE_CLAIMED_BITMAP_PREFIX, word_index);
+    if runtime::has_key(&word_key) {
+        runtime::remove_key(&word_key);
+    }
+
+    let new_data_uref = storage::new_uref(word);
+    runtime::put_key(&word_key, new_data_uref.into());
+}
+
+pub fn load_paused() -> bool {
+    if !runtime::has_key(keys::KEY_PAUSED) {
+        return false;
+    }
+    load_value(keys::KEY_PAUSED)
+}
+
+pub fn save_paused(paused: bool) {
+    overwrite_value(keys::KEY_PAUSED, paused);
+}
+
+// Zero means no bound on that side
+pub fn load_claim_window() -> (u64, u64) {
+    let claim_start: u64 = if runtime::has_key(keys::KEY_CLAIM_START) {
+        load_value(keys::KEY_CLAIM_START)
+    } else {
+        0
+    };
+    let claim_end: u64 = if runtime::has_key(keys::KEY_CLAIM_END) {
+        load_value(keys::KEY_CLAIM_END)
+    } else {
+        0
+    };
+    (claim_start, claim_end)
+}
+
+pub fn save_claim_window(claim_start: u64, claim_end: u64) {
+    overwrite_value(keys::KEY_CLAIM_START, claim_start);
+    overwrite_value(keys::KEY_CLAIM_END, claim_end);
+}
+
+pub fn load_swept() -> bool {
+    if !runtime::has_key(keys::KEY_SWEPT) {
+        return false;
+    }
+    load_value(keys::KEY_SWEPT)
+}
+
+pub fn save_swept() {
+    overwrite_value(keys::KEY_SWEPT, true);
+}
+
+// Sum of every snapshot balance, including the whole merkle snapshot
+pub fn load_total_liabilities() -> U512 {
+    load_amount_or_zero(keys::KEY_TOTAL_LIABILITIES)
+}
+
+pub fn save_total_liabilities(value: U512) {
+    overwrite_value(keys::KEY_TOTAL_LIABILITIES, value);
+}
+
+pub fn load_total_claimed() -> U512 {
+    load_amount_or_zero(keys::KEY_TOTAL_CLAIMED)
+}
+
+pub fn save_total_claimed(value: U512) {
+    overwrite_value(keys::KEY_TOTAL_CLAIMED, value);
+}
+
+// Liabilities left unclaimed when the purse is swept
+pub fn load_total_expired() -> U512 {
+    load_amount_or_zero(keys::KEY_TOTAL_EXPIRED)
+}
+
+pub fn save_total_expired(value: U512) {
+    overwrite_value(keys::KEY_TOTAL_EXPIRED, value);
+}
+
+pub fn check_multisig_config_existence() -> bool {
+    runtime::has_key(keys::KEY_MULTISIG_THRESHOLD)
+}
+
+pub fn load_multisig_signers() -> Vec<PublicKey> {
+    load_value(keys::KEY_MULTISIG_SIGNERS)
+}
+
+pub fn load_multisig_threshold() -> U512 {
+    load_value(keys::KEY_MULTISIG_THRESHOLD)
+}
+
+pub fn save_multisig_config(signers: Vec<PublicKey>, threshold: U512) {
+    overwrite_value(keys::KEY_MULTISIG_SIGNERS, signers);
+    overwrite_value(keys::KEY_MULTISIG_THRESHOLD, threshold);
+}
+
+pub fn load_proposal_count() -> U512 {
+    if !runtime::has_key(keys::KEY_PROPOSAL_COUNT) {
+        return U512::from(0);
+    }
+    load_value(keys::KEY_PROPOSAL_COUNT)
+}
+
+pub fn save_proposal_count(count: U512) {
+    overwrite_value(keys::KEY_PROPOSAL_COUNT, count);
+}
+
+pub fn load_pending_proposals() -> Vec<U512> {
+    if !runtime::has_key(keys::KEY_PENDING_PROPOSALS) {
+        return Vec::new();
+    }
+    load_value(keys::KEY_PENDING_PROPOSALS)
+}
+
+pub fn save_pending_proposals(proposal_ids: Vec<U512>) {
+    overwrite_value(keys::KEY_PENDING_PROPOSALS, proposal_ids);
+}
+
+fn proposal_key(proposal_id: U512) -> String {
+    format!("{}{}", keys::KEY_PROPOSAL_PREFIX, proposal_id)
+}
+
+pub fn check_proposal_data_existence(proposal_id: U512) -> bool {
+    runtime::has_key(&proposal_key(proposal_id))
+}
+
+pub fn load_proposal_data(proposal_id: U512) -> UnitProposalData {
+    UnitProposalData::restore(load_value(&proposal_key(proposal_id)))
+}
+
+pub fn save_proposal_data(proposal_id: U512, unit_data: &UnitProposalData) {
+    overwrite_value(&proposal_key(proposal_id), unit_data.organize());
+}
+
+// Approval of a signer is the existence of "multisig_approval_<proposal id>_<signer hex>"
+fn approval_key(proposal_id: U512, signer: PublicKey) -> String {
+    format!(
+        "{}{}_{}",
+        keys::KEY_PROPOSAL_APPROVAL_PREFIX,
+        proposal_id,
+        to_hex_string(signer)
+    )
+}
+
+pub fn check_proposal_approval(proposal_id: U512, signer: PublicKey) -> bool {
+    runtime::has_key(&approval_key(proposal_id, signer))
+}
+
+pub fn save_proposal_approval(proposal_id: U512, signer: PublicKey) {
+    overwrite_value(&approval_key(proposal_id, signer), true);
+}
+
+fn load_value<T: CLTyped + FromBytes>(key_name: &str) -> T {
+    let value_uref: URef = runtime::get_key(key_name)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .try_into()
+        .unwrap_or_revert();
+
+    storage::read(value_uref)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_revert_with(ApiError::ValueNotFound)
+}
+
+pub fn load_receipt_count() -> U512 {
+    load_amount_or_zero(keys::KEY_RECEIPT_COUNT)
+}
+
+pub fn save_receipt_count(value: U512) {
+    overwrite_value(keys::KEY_RECEIPT_COUNT, value);
+}
+
+pub fn check_receipt_existence(ver1_address: &str) -> bool {
+    runtime::has_key(&receipt_key(ver1_address))
+}
+
+pub fn load_receipt(ver1_address: &str) -> UnitReceiptData {
+    UnitReceiptData::restore(load_value(&receipt_key(ver1_address)))
+}
+
+pub fn save_receipt(ver1_address: &str, receipt: &UnitReceiptData) {
+    overwrite_value(&receipt_key(ver1_address), receipt.organize());
+}
+
+// Ver1 addresses of the account which have unpaid amounts of partial claims
+pub fn load_pending_claims(mainnet_address: PublicKey) -> Vec<String> {
+    let key_name = pending_claims_key(mainnet_address);
+    if !runtime::has_key(&key_name) {
+        return Vec::new();
+    }
+    load_value(&key_name)
+}
+
+pub fn save_pending_claims(mainnet_address: PublicKey, ver1_addresses: Vec<String>) {
+    overwrite_value(&pending_claims_key(mainnet_address), ver1_addresses);
+}
+
+fn pending_claims_key(mainnet_address: PublicKey) -> String {
+    format!(
+        "{}{}",
+        keys::KEY_PENDING_CLAIMS_PREFIX,
+        to_hex_string(mainnet_address)
+    )
+}
+
+// Denylist of ver1 addresses. An address is denied if the key "denylist_<ver1 address>" exists.
+fn denylist_key(ver1_address: &str) -> String {
+    format!("{}{}", keys::KEY_DENYLIST_PREFIX, ver1_address)
+}
+
+pub fn check_denylisted(ver1_address: &str) -> bool {
+    runtime::has_key(&denylist_key(ver1_address))
+}
+
+pub fn save_denylisted(ver1_address: &str) {
+    let key = denylist_key(ver1_address);
+    if runtime::has_key(&key) {
+        return;
+    }
+    let new_data_uref = storage::new_uref(true);
+    runtime::put_key(&key, new_data_uref.into());
+}
+
+pub fn remove_denylisted(ver1_address: &str) {
+    runtime::remove_key(&denylist_key(ver1_address));
+}
+
+// Ver1 addresses claimed by the account, in order of the claims
+pub fn load_account_receipts(mainnet_address: PublicKey) -> Vec<String> {
+    let key_name = account_receipts_key(mainnet_address);
+    if !runtime::has_key(&key_name) {
+        return Vec::new();
+    }
+    load_value(&key_name)
+}
+
+pub fn save_account_receipts(mainnet_address: PublicKey, ver1_addresses: Vec<String>) {
+    overwrite_value(&account_receipts_key(mainnet_address), ver1_addresses);
+}
+
+fn receipt_key(ver1_address: &str) -> String {
+    format!("{}{}", keys::KEY_RECEIPT_PREFIX, ver1_address)
+}
+
+fn account_receipts_key(mainnet_address: PublicKey) -> String {
+    format!(
+        "{}{}",
+        keys::KEY_ACCOUNT_RECEIPTS_PREFIX,
+        to_hex_string(mainnet_address)
+    )
+}
+
+pub fn load_event_count() -> U512 {
+    load_amount_or_zero(keys::KEY_EVENT_COUNT)
+}
+
+pub fn save_event_count(value: U512) {
+    overwrite_value(keys::KEY_EVENT_COUNT, value);
+}
+
+pub fn load_event(seq: U512) -> BTreeMap<String, String> {
+    load_value(&event_key(seq))
+}
+
+pub fn save_event(seq: U512, event: BTreeMap<String, String>) {
+    overwrite_value(&event_key(seq), event);
+}
+
+fn event_key(seq: U512) -> String {
+    format!("{}{}", keys::KEY_EVENT_PREFIX, seq)
+}
+
+pub fn load_swap_stats() -> BTreeMap<String, String> {
+    if !runtime::has_key(keys::KEY_SWAP_STATS) {
+        return BTreeMap::new();
+    }
+    load_value(keys::KEY_SWAP_STATS)
+}
+
+pub fn save_swap_stats(stats: BTreeMap<String, String>) {
+    overwrite_value(keys::KEY_SWAP_STATS, stats);
+}
+
+fn load_amount_or_zero(key_name: &str) -> U512 {
+    if !runtime::has_key(key_name) {
+        return U512::from(0);
+    }
+    load_value(key_name)
+}
+
+fn overwrite_value<T: CLTyped + ToBytes>(key_name: &str, value: T) {
+    if runtime::has_key(key_name) {
+        runtime::remove_key(key_name);
+    }
+    let new_data_uref = storage::new_uref(value);
+    runtime::put_key(key_name, new_data_uref.into());
+}
@@ -1,12 +1,133 @@
 extern crate hex;
 
 use super::error::Error as SwapError;
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 use contract::contract_api::runtime;
 use ripemd160::Ripemd160;
-use secp256k1::{self, Message, PublicKey as Ver1PubKey, Signature};
+use secp256k1::{self, Message, PublicKey as Ver1PubKey, RecoveryId, Signature};
 use sha2::{Digest, Sha256};
 
+const SWAP_MESSAGE_DOMAIN_TAG: &str = "Hdac token swap";
+
+// Network parameters of Hdac ver1 mainnet.
+// 'H' for pubkey hash, '4' for script hash, and "HDAC" for checksum XOR.
+pub const VER1_MAINNET_PUBKEY_HASH_PREFIX: u8 = 0x28;
+pub const VER1_MAINNET_SCRIPT_HASH_PREFIX: u8 = 0x08;
+pub const VER1_MAINNET_CHECKSUM_XOR: u32 = 0x4844_4143;
+
+const COMPRESSED_PUBKEY_LENGTH: usize = 33;
+const UNCOMPRESSED_PUBKEY_LENGTH: usize = 65;
+const HASHED_MESSAGE_LENGTH: usize = 32;
+
+const OP_1: u8 = 0x51;
+const OP_16: u8 = 0x60;
+const OP_CHECKMULTISIG: u8 = 0xae;
+
+// Prefix of "signmessage" in Hdac ver1 wallet
+const VER1_MESSAGE_MAGIC: &str = "Hdac Signed Message:\n";
+
+// Header byte of compact recoverable signature.
+// 27 ~ 30 for uncompressed public key, 31 ~ 34 for compressed one.
+const COMPACT_SIGNATURE_HEADER_BASE: u8 = 27;
+const COMPACT_SIGNATURE_HEADER_COMPRESSED: u8 = 31;
+const COMPACT_SIGNATURE_HEADER_MAX: u8 = 34;
+
+// Message which the ver1 key should sign for the swap.
+// It binds the signature to this swap contract, the claiming mainnet account and the ver1 address,
+// so that any other signature made by the ver1 key cannot be replayed.
+pub fn swap_message(
+    contract_hash: [u8; 32],
+    mainnet_pubkey_hex: &str,
+    ver1_address: &str,
+) -> String {
+    format!(
+        "{}:{}:{}:{}",
+        SWAP_MESSAGE_DOMAIN_TAG,
+        hex::encode(contract_hash),
+        mainnet_pubkey_hex,
+        ver1_address
+    )
+}
+
+pub fn swap_message_digest(message: &str) -> String {
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input(message.as_bytes());
+    hex::encode(sha256hasher.result())
+}
+
+// Digest of "signmessage":
+// SHA256(SHA256(varint(len(magic)) || magic || varint(len(message)) || message))
+pub fn signed_message_digest(message: &str) -> [u8; 32] {
+    let mut payload: Vec<u8> = Vec::new();
+    write_compact_size(&mut payload, VER1_MESSAGE_MAGIC.len());
+    payload.extend(VER1_MESSAGE_MAGIC.as_bytes());
+    write_compact_size(&mut payload, message.len());
+    payload.extend(message.as_bytes());
+
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input(payload);
+    let sha256res_1st = sha256hasher.result();
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input(sha256res_1st);
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(sha256hasher.result().as_slice());
+    res
+}
+
+fn write_compact_size(buffer: &mut Vec<u8>, size: usize) {
+    if size < 0xfd {
+        buffer.push(size as u8);
+    } else if size <= 0xffff {
+        buffer.push(0xfd);
+        buffer.extend(&(size as u16).to_le_bytes());
+    } else if size <= 0xffff_ffff {
+        buffer.push(0xfe);
+        buffer.extend(&(size as u32).to_le_bytes());
+    } else {
+        buffer.push(0xff);
+        buffer.extend(&(size as u64).to_le_bytes());
+    }
+}
+
+// Recovers the public key from base64 encoded 65-byted compact signature of "signmessage".
+// Returns hex of the public key, serialized as the header byte tells.
+pub fn recover_ver1_pubkey(message: &str, signature_base64: String) -> String {
+    let signature_vec = match base64::decode(&signature_base64) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::SignatureBase64DecodeFail),
+    };
+    if signature_vec.len() != 65 {
+        runtime::revert(SwapError::InvalidVer1Signature);
+    }
+
+    let header = signature_vec[0];
+    if header < COMPACT_SIGNATURE_HEADER_BASE || header > COMPACT_SIGNATURE_HEADER_MAX {
+        runtime::revert(SwapError::InvalidVer1Signature);
+    }
+    let is_compressed = header >= COMPACT_SIGNATURE_HEADER_COMPRESSED;
+    let recovery_id = match RecoveryId::parse((header - COMPACT_SIGNATURE_HEADER_BASE) & 3) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::InvalidVer1Signature),
+    };
+    let signature_obj = match Signature::parse_slice(&signature_vec[1..]) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::InvalidVer1Signature),
+    };
+
+    let message_struct = Message::parse(&signed_message_digest(message));
+    let ver1_pubkey = match secp256k1::recover(&message_struct, &signature_obj, &recovery_id) {
+        Ok(val) => val,
+        Err(_) => runtime::revert(SwapError::PublicKeyRecoveryFail),
+    };
+
+    if is_compressed {
+        hex::encode(&ver1_pubkey.serialize_compressed()[..])
+    } else {
+        hex::encode(&ver1_pubkey.serialize()[..])
+    }
+}
+
 pub fn signature_verification(
     ver1_pubkey_hex: String,
     message: String,
@@ -16,63 +137,255 @@ pub fn signature_verification(
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::PublicKeyDecodeFail),
     };
-    let mut ver1_pubkey_byted_arr: [u8; 33] = [0u8; 33];
-    ver1_pubkey_byted_arr.copy_from_slice(&ver1_pubkey_bytes.as_slice()[0..33]);
-    let ver1_pubkey: Ver1PubKey = match Ver1PubKey::parse_compressed(&ver1_pubkey_byted_arr) {
+    let ver1_pubkey = parse_ver1_pubkey(&ver1_pubkey_bytes);
+
+    let message_struct = parse_hashed_message(message);
+    let signature_obj = parse_signature(signature_hex);
+
+    secp256k1::verify(&message_struct, &signature_obj, &ver1_pubkey)
+}
+
+// Verifies signatures in the same way as OP_CHECKMULTISIG.
+// Signatures should be ordered as their public keys are in the redeem script.
+pub fn multisig_verification(
+    ver1_pubkeys: &[Ver1PubKey],
+    required_signatures: usize,
+    message: String,
+    signature_hex: Vec<String>,
+) -> bool {
+    let message_struct = parse_hashed_message(message);
+
+    let mut pubkey_idx = 0;
+    let mut num_of_valid_signatures = 0;
+    for signature in signature_hex {
+        let signature_obj = parse_signature(signature);
+        while pubkey_idx < ver1_pubkeys.len() {
+            pubkey_idx += 1;
+            if secp256k1::verify(
+                &message_struct,
+                &signature_obj,
+                &ver1_pubkeys[pubkey_idx - 1],
+            ) {
+                num_of_valid_signatures += 1;
+                break;
+            }
+        }
+    }
+
+    num_of_valid_signatures >= required_signatures
+}
+
+// Both of compressed (33 bytes) and uncompressed (65 bytes) keys are used in ver1.
+fn parse_ver1_pubkey(ver1_pubkey_bytes: &[u8]) -> Ver1PubKey {
+    let parsed = match ver1_pubkey_bytes.len() {
+        COMPRESSED_PUBKEY_LENGTH => {
+            let mut ver1_pubkey_byted_arr: [u8; COMPRESSED_PUBKEY_LENGTH] =
+                [0u8; COMPRESSED_PUBKEY_LENGTH];
+            ver1_pubkey_byted_arr.copy_from_slice(ver1_pubkey_bytes);
+            Ver1PubKey::parse_compressed(&ver1_pubkey_byted_arr)
+        }
+        UNCOMPRESSED_PUBKEY_LENGTH => {
+            let mut ver1_pubkey_byted_arr: [u8; UNCOMPRESSED_PUBKEY_LENGTH] =
+                [0u8; UNCOMPRESSED_PUBKEY_LENGTH];
+            ver1_pubkey_byted_arr.copy_from_slice(ver1_pubkey_bytes);
+            Ver1PubKey::parse(&ver1_pubkey_byted_arr)
+        }
+        _ => runtime::revert(SwapError::InvalidHexOfPublicKey),
+    };
+
+    match parsed {
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::InvalidHexOfPublicKey),
-    };
+    }
+}
 
+fn parse_hashed_message(message: String) -> Message {
     // Message is already hashed. Don't have to hash again in here.
     let message_bytes = match hex::decode(message) {
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::MessageDecodeFail),
     };
-    let mut hashed_msg: [u8; 32] = [0u8; 32];
+    if message_bytes.len() != HASHED_MESSAGE_LENGTH {
+        runtime::revert(SwapError::MessageDecodeFail);
+    }
+    let mut hashed_msg: [u8; HASHED_MESSAGE_LENGTH] = [0u8; HASHED_MESSAGE_LENGTH];
     hashed_msg.copy_from_slice(&message_bytes);
-    let message_struct = Message::parse(&hashed_msg);
+    Message::parse(&hashed_msg)
+}
 
+fn parse_signature(signature_hex: String) -> Signature {
     // 64-byted signature, not DER-encoded 71 byte
     let signature_vec = match hex::decode(signature_hex) {
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::SignatureHexDecodeFail),
     };
     let signature_byte: &[u8] = signature_vec.as_slice();
-    let signature_obj = match Signature::parse_slice(signature_byte) {
+    match Signature::parse_slice(signature_byte) {
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::InvalidVer1Signature),
+    }
+}
+
+// Multisig redeem script: OP_m <pubkey> ... <pubkey> OP_n OP_CHECKMULTISIG
+// Returns the number of required signatures and the public keys.
+pub fn parse_multisig_redeem_script(redeem_script: &[u8]) -> (usize, Vec<Ver1PubKey>) {
+    let script_len = redeem_script.len();
+    if script_len < 3 || redeem_script[script_len - 1] != OP_CHECKMULTISIG {
+        runtime::revert(SwapError::InvalidRedeemScript);
+    }
+
+    let required_signatures = decode_small_int(redeem_script[0]);
+    let num_of_pubkeys = decode_small_int(redeem_script[script_len - 2]);
+
+    let mut ver1_pubkeys: Vec<Ver1PubKey> = Vec::new();
+    let mut idx = 1;
+    while idx < script_len - 2 {
+        // Push opcode of a public key equals to its length
+        let pubkey_len = redeem_script[idx] as usize;
+        if pubkey_len != COMPRESSED_PUBKEY_LENGTH && pubkey_len != UNCOMPRESSED_PUBKEY_LENGTH {
+            runtime::revert(SwapError::InvalidRedeemScript);
+        }
+        if idx + 1 + pubkey_len > script_len - 2 {
+            runtime::revert(SwapError::InvalidRedeemScript);
+        }
+
+        let ver1_pubkey =
+            match Ver1PubKey::parse_slice(&redeem_script[idx + 1..idx + 1 + pubkey_len], None) {
+                Ok(val) => val,
+                Err(_) => runtime::revert(SwapError::InvalidRedeemScript),
+            };
+        ver1_pubkeys.push(ver1_pubkey);
+        idx += 1 + pubkey_len;
+    }
+
+    if required_signatures == 0
+        || required_signatures > num_of_pubkeys
+        || num_of_pubkeys != ver1_pubkeys.len()
+    {
+        runtime::revert(SwapError::InvalidRedeemScript);
+    }
+
+    (required_signatures, ver1_pubkeys)
+}
+
+// OP_1 ~ OP_16
+fn decode_small_int(opcode: u8) -> usize {
+    if opcode < OP_1 || opcode > OP_16 {
+        runtime::revert(SwapError::InvalidRedeemScript);
+    }
+    (opcode - OP_1 + 1) as usize
+}
+
+// 'H' + hash160 + Hdac ver1 checksum = 1 + 20 + 4 = 25 bytes
+const VER1_ADDRESS_LENGTH: usize = 25;
+
+// Address version bytes and checksum XOR seed of the ver1 chain.
+// Set at install time, so that the same contract serves mainnet, testnet and private chains.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ver1NetworkParams {
+    pub pubkey_hash_prefix: u8,
+    pub script_hash_prefix: u8,
+    pub checksum_xor: u32,
+}
+
+impl Default for Ver1NetworkParams {
+    fn default() -> Self {
+        Ver1NetworkParams {
+            pubkey_hash_prefix: VER1_MAINNET_PUBKEY_HASH_PREFIX,
+            script_hash_prefix: VER1_MAINNET_SCRIPT_HASH_PREFIX,
+            checksum_xor: VER1_MAINNET_CHECKSUM_XOR,
+        }
+    }
+}
+
+pub fn validate_ver1_address(ver1_address: &str, network_params: &Ver1NetworkParams) -> bool {
+    match bs58::decode(ver1_address).into_vec() {
+        Ok(decoded) => {
+            decoded.len() == VER1_ADDRESS_LENGTH
+                && (decoded[0] == network_params.pubkey_hash_prefix
+                    || decoded[0] == network_params.script_hash_prefix)
+        }
+        Err(_) => false,
+    }
+}
+
+// Checks the Hdac checksum of the address, in the same way as the address is derived
+pub fn verify_ver1_address_checksum(
+    ver1_address: &str,
+    network_params: &Ver1NetworkParams,
+) -> bool {
+    let decoded = match bs58::decode(ver1_address).into_vec() {
+        Ok(val) => val,
+        Err(_) => return false,
     };
+    if decoded.len() != VER1_ADDRESS_LENGTH {
+        return false;
+    }
 
-    secp256k1::verify(&message_struct, &signature_obj, &ver1_pubkey)
+    let (payload, checksum) = decoded.split_at(VER1_ADDRESS_LENGTH - 4);
+    ver1_checksum(payload, network_params.checksum_xor).as_slice() == checksum
 }
 
-pub fn derive_ver1_address(ver1_pubkey_hex: String) -> String {
+pub fn derive_ver1_address(ver1_pubkey_hex: String, network_params: &Ver1NetworkParams) -> String {
     let ver1_pubkey_bytes = match hex::decode(ver1_pubkey_hex) {
         Ok(val) => val,
         Err(_) => runtime::revert(SwapError::PublicKeyDecodeFail),
     };
 
-    // hash160
+    encode_ver1_address(
+        network_params.pubkey_hash_prefix,
+        hash160(&ver1_pubkey_bytes),
+        network_params.checksum_xor,
+    )
+}
+
+pub fn derive_ver1_p2sh_address(
+    redeem_script: &[u8],
+    network_params: &Ver1NetworkParams,
+) -> String {
+    encode_ver1_address(
+        network_params.script_hash_prefix,
+        hash160(redeem_script),
+        network_params.checksum_xor,
+    )
+}
+
+fn hash160(bytes: &[u8]) -> Vec<u8> {
     let mut sha256hasher = Sha256::new();
-    sha256hasher.input(ver1_pubkey_bytes);
+    sha256hasher.input(bytes);
     let sha256res = sha256hasher.result();
 
     let mut ripemd160hasher = Ripemd160::new();
     ripemd160hasher.input(sha256res);
-    let hash160res = ripemd160hasher.result();
+    ripemd160hasher.result().to_vec()
+}
 
+fn encode_ver1_address(prefix: u8, hash160res: Vec<u8>, checksum_xor: u32) -> String {
     // payload
     let mut payload: Vec<u8> = Vec::new();
-    let prefix: u8 = 0x28;
     payload.push(prefix);
     for item in hash160res.iter() {
         payload.push(*item);
     }
 
-    // Hdac checksum
+    let buffered = ver1_checksum(&payload, checksum_xor);
+
+    // Prefix + hash160 + Hdac ver1 checksum = 1 + 20 + 4 = 25 bytes
+    let res = {
+        let mut res = Vec::with_capacity(payload.len() + buffered.len());
+        res.extend(payload);
+        res.extend(buffered);
+        res
+    };
+
+    bs58::encode(res).into_string()
+}
+
+// Hdac checksum
+fn ver1_checksum(payload: &[u8], checksum_xor: u32) -> Vec<u8> {
     // 1. checksum body
     let mut sha256hasher = Sha256::new();
-    sha256hasher.input(payload.clone());
+    sha256hasher.input(payload);
     let sha256res_for_checksum_1st = sha256hasher.result();
     let mut sha256hasher = Sha256::new();
     sha256hasher.input(sha256res_for_checksum_1st);
@@ -80,11 +393,8 @@ pub fn derive_ver1_address(ver1_pubkey_hex: String) -> String {
     let (sha256res_for_checksum_sliced, _) = sha256res_for_checksum_2nd.split_at_mut(4);
     sha256res_for_checksum_sliced.reverse();
 
-    // 2. 48444143
-    let mut dummy_bytes = match hex::decode("48444143") {
-        Ok(val) => val,
-        Err(_) => runtime::revert(SwapError::MessageDecodeFail),
-    };
+    // 2. 48444143 in mainnet
+    let mut dummy_bytes = checksum_xor.to_be_bytes();
     dummy_bytes.reverse();
 
     // 3. XOR
@@ -94,13 +404,5 @@ pub fn derive_ver1_address(ver1_pubkey_hex: String) -> String {
     }
     buffered.reverse();
 
-    // 'H' + hash160 + Hdac ver1 checksum = 1 + 20 + 4 = 25 bytes
-    let res = {
-        let mut res = Vec::with_capacity(payload.len() + buffered.len());
-        res.extend(payload);
-        res.extend(buffered);
-        res
-    };
-
-    bs58::encode(res).into_string()
+    buffered
 }
@@ -1,12 +1,12 @@
 mod error;
 
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
 use contract::{
     contract_api::{account, runtime, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::{account::PublicKey, ApiError, ContractRef, Key, TransferResult, URef, U512};
+use types::{account::PublicKey, ApiError, CLValue, ContractRef, Key, TransferResult, URef, U512};
 
 use error::Error;
 
@@ -17,16 +17,105 @@ pub mod method_names {
         pub const NAME_SWAP_HASH: &str = "swap_hash";
         pub const METHOD_INSERT_KYC_ALLOWANCE_CAP: &str = swap::METHOD_INSERT_KYC_ALLOWANCE_CAP;
         pub const METHOD_INSERT_SNAPSHOT_RECORD: &str = swap::METHOD_INSERT_SNAPSHOT_RECORD;
+        pub const METHOD_INSERT_SNAPSHOT_RECORDS: &str = swap::METHOD_INSERT_SNAPSHOT_RECORDS;
+        pub const METHOD_AMEND_SNAPSHOT_RECORD: &str = swap::METHOD_AMEND_SNAPSHOT_RECORD;
         pub const METHOD_INSERT_KYC_DATA: &str = swap::METHOD_INSERT_KYC_DATA;
         pub const METHOD_UPDATE_KYC_LEVEL: &str = swap::METHOD_UPDATE_KYC_LEVEL;
         pub const METHOD_GET_TOKEN: &str = swap::METHOD_GET_TOKEN;
+        pub const METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE: &str =
+            swap::METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE;
+        pub const METHOD_INSERT_SNAPSHOT_MERKLE_ROOT: &str =
+            swap::METHOD_INSERT_SNAPSHOT_MERKLE_ROOT;
+        pub const METHOD_GET_TOKEN_WITH_MERKLE_PROOF: &str =
+            swap::METHOD_GET_TOKEN_WITH_MERKLE_PROOF;
+        pub const METHOD_GET_TOKEN_WITH_MULTISIG: &str = swap::METHOD_GET_TOKEN_WITH_MULTISIG;
+        pub const METHOD_GET_SNAPSHOT_RECORD: &str = swap::METHOD_GET_SNAPSHOT_RECORD;
+        pub const METHOD_GET_KYC_RECORD: &str = swap::METHOD_GET_KYC_RECORD;
+        pub const METHOD_GET_ALLOWANCE_CAP: &str = swap::METHOD_GET_ALLOWANCE_CAP;
+        pub const METHOD_GET_ADMIN: &str = swap::METHOD_GET_ADMIN;
+        pub const METHOD_PROPOSE_ADMIN: &str = swap::METHOD_PROPOSE_ADMIN;
+        pub const METHOD_ACCEPT_ADMIN: &str = swap::METHOD_ACCEPT_ADMIN;
+        pub const METHOD_GRANT_ROLE: &str = swap::METHOD_GRANT_ROLE;
+        pub const METHOD_REVOKE_ROLE: &str = swap::METHOD_REVOKE_ROLE;
+        pub const METHOD_REGISTER_SWAP_HASH: &str = "register_swap_hash";
+        pub const METHOD_SET_MULTISIG_CONFIG: &str = swap::METHOD_SET_MULTISIG_CONFIG;
+        pub const METHOD_PROPOSE_ACTION: &str = swap::METHOD_PROPOSE_ACTION;
+        pub const METHOD_APPROVE_PROPOSAL: &str = swap::METHOD_APPROVE_PROPOSAL;
+        pub const METHOD_EXECUTE_PROPOSAL: &str = swap::METHOD_EXECUTE_PROPOSAL;
+        pub const METHOD_CANCEL_PROPOSAL: &str = swap::METHOD_CANCEL_PROPOSAL;
+        pub const METHOD_GET_PROPOSAL: &str = swap::METHOD_GET_PROPOSAL;
+        pub const METHOD_GET_PENDING_PROPOSALS: &str = swap::METHOD_GET_PENDING_PROPOSALS;
+        pub const METHOD_PAUSE_SWAP: &str = swap::METHOD_PAUSE_SWAP;
+        pub const METHOD_UNPAUSE_SWAP: &str = swap::METHOD_UNPAUSE_SWAP;
+        pub const METHOD_SET_CLAIM_WINDOW: &str = swap::METHOD_SET_CLAIM_WINDOW;
+        pub const METHOD_SWEEP_UNCLAIMED: &str = swap::METHOD_SWEEP_UNCLAIMED;
+        pub const METHOD_GET_RESERVE_STATUS: &str = swap::METHOD_GET_RESERVE_STATUS;
+        pub const METHOD_GET_STATS: &str = swap::METHOD_GET_STATS;
+        pub const METHOD_GET_RECEIPT: &str = swap::METHOD_GET_RECEIPT;
+        pub const METHOD_GET_RECEIPTS_BY_ACCOUNT: &str = swap::METHOD_GET_RECEIPTS_BY_ACCOUNT;
+        pub const METHOD_GET_EVENTS: &str = swap::METHOD_GET_EVENTS;
+        pub const METHOD_SET_KYC_TIER: &str = swap::METHOD_SET_KYC_TIER;
+        pub const METHOD_GET_KYC_TIER: &str = swap::METHOD_GET_KYC_TIER;
+        pub const METHOD_RENEW_KYC: &str = swap::METHOD_RENEW_KYC;
+        pub const METHOD_GET_EXPIRING_KYC_RECORDS: &str = swap::METHOD_GET_EXPIRING_KYC_RECORDS;
+        pub const METHOD_REVOKE_KYC: &str = swap::METHOD_REVOKE_KYC;
+        pub const METHOD_FREEZE_ACCOUNT: &str = swap::METHOD_FREEZE_ACCOUNT;
+        pub const METHOD_UNFREEZE_ACCOUNT: &str = swap::METHOD_UNFREEZE_ACCOUNT;
+        pub const METHOD_ADD_TO_DENYLIST: &str = swap::METHOD_ADD_TO_DENYLIST;
+        pub const METHOD_REMOVE_FROM_DENYLIST: &str = swap::METHOD_REMOVE_FROM_DENYLIST;
+        pub const METHOD_IS_DENYLISTED: &str = swap::METHOD_IS_DENYLISTED;
+        pub const METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT: &str =
+            swap::METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT;
+        pub const METHOD_CLAIM_PENDING: &str = swap::METHOD_CLAIM_PENDING;
     }
     pub mod swap {
         pub const METHOD_INSERT_KYC_ALLOWANCE_CAP: &str = "insert_kyc_allowance_cap";
         pub const METHOD_INSERT_SNAPSHOT_RECORD: &str = "insert_snapshot_record";
+        pub const METHOD_INSERT_SNAPSHOT_RECORDS: &str = "insert_snapshot_records";
+        pub const METHOD_AMEND_SNAPSHOT_RECORD: &str = "amend_snapshot_record";
         pub const METHOD_INSERT_KYC_DATA: &str = "insert_kyc_data";
         pub const METHOD_UPDATE_KYC_LEVEL: &str = "update_kyc_level";
         pub const METHOD_GET_TOKEN: &str = "get_token";
+        pub const METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE: &str = "get_token_with_signed_message";
+        pub const METHOD_INSERT_SNAPSHOT_MERKLE_ROOT: &str = "insert_snapshot_merkle_root";
+        pub const METHOD_GET_TOKEN_WITH_MERKLE_PROOF: &str = "get_token_with_merkle_proof";
+        pub const METHOD_GET_TOKEN_WITH_MULTISIG: &str = "get_token_with_multisig";
+        pub const METHOD_GET_SNAPSHOT_RECORD: &str = "get_snapshot_record";
+        pub const METHOD_GET_KYC_RECORD: &str = "get_kyc_record";
+        pub const METHOD_GET_ALLOWANCE_CAP: &str = "get_allowance_cap";
+        pub const METHOD_GET_ADMIN: &str = "get_admin";
+        pub const METHOD_PROPOSE_ADMIN: &str = "propose_admin";
+        pub const METHOD_ACCEPT_ADMIN: &str = "accept_admin";
+        pub const METHOD_GRANT_ROLE: &str = "grant_role";
+        pub const METHOD_REVOKE_ROLE: &str = "revoke_role";
+        pub const METHOD_SET_MULTISIG_CONFIG: &str = "set_multisig_config";
+        pub const METHOD_PROPOSE_ACTION: &str = "propose_action";
+        pub const METHOD_APPROVE_PROPOSAL: &str = "approve_proposal";
+        pub const METHOD_EXECUTE_PROPOSAL: &str = "execute_proposal";
+        pub const METHOD_CANCEL_PROPOSAL: &str = "cancel_proposal";
+        pub const METHOD_GET_PROPOSAL: &str = "get_proposal";
+        pub const METHOD_GET_PENDING_PROPOSALS: &str = "get_pending_proposals";
+        pub const METHOD_PAUSE_SWAP: &str = "pause_swap";
+        pub const METHOD_UNPAUSE_SWAP: &str = "unpause_swap";
+        pub const METHOD_SET_CLAIM_WINDOW: &str = "set_claim_window";
+        pub const METHOD_SWEEP_UNCLAIMED: &str = "sweep_unclaimed";
+        pub const METHOD_GET_RESERVE_STATUS: &str = "get_reserve_status";
+        pub const METHOD_GET_STATS: &str = "get_stats";
+        pub const METHOD_GET_RECEIPT: &str = "get_receipt";
+        pub const METHOD_GET_RECEIPTS_BY_ACCOUNT: &str = "get_receipts_by_account";
+        pub const METHOD_GET_EVENTS: &str = "get_events";
+        pub const METHOD_SET_KYC_TIER: &str = "set_kyc_tier";
+        pub const METHOD_GET_KYC_TIER: &str = "get_kyc_tier";
+        pub const METHOD_RENEW_KYC: &str = "renew_kyc";
+        pub const METHOD_GET_EXPIRING_KYC_RECORDS: &str = "get_expiring_kyc_records";
+        pub const METHOD_REVOKE_KYC: &str = "revoke_kyc";
+        pub const METHOD_FREEZE_ACCOUNT: &str = "freeze_account";
+        pub const METHOD_UNFREEZE_ACCOUNT: &str = "unfreeze_account";
+        pub const METHOD_ADD_TO_DENYLIST: &str = "add_to_denylist";
+        pub const METHOD_REMOVE_FROM_DENYLIST: &str = "remove_from_denylist";
+        pub const METHOD_IS_DENYLISTED: &str = "is_denylisted";
+        pub const METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT: &str = "replace_snapshot_merkle_root";
+        pub const METHOD_CLAIM_PENDING: &str = "claim_pending";
 
         pub const METHOD_GET_CONTRACT_PURSE: &str = "get_contract_purse";
     }
@@ -37,9 +126,59 @@ const BIGSUN_TO_HDAC: u64 = 1_000_000_000_000_000_000_u64;
 pub enum Api {
     InsertKYCAllowanceCap(U512),
     InsertSnapshotRecord(String, U512),
-    InsertKYCData(PublicKey, U512),
-    UpdateKYCLevel(PublicKey, U512),
-    GetToken(Key, Vec<String>, Vec<String>, Vec<String>),
+    InsertSnapshotRecords(Vec<String>, Vec<U512>),
+    AmendSnapshotRecord(String, U512),
+    InsertKYCData(PublicKey, U512, u64),
+    UpdateKYCLevel(PublicKey, U512, Option<u64>),
+    GetToken(Key, Vec<String>, Vec<String>, bool),
+    GetTokenWithSignedMessage(Key, Vec<String>, Vec<String>),
+    InsertSnapshotMerkleRoot(String, U512),
+    GetTokenWithMerkleProof(
+        Key,
+        Vec<String>,
+        Vec<String>,
+        Vec<u64>,
+        Vec<U512>,
+        Vec<Vec<String>>,
+    ),
+    GetTokenWithMultisig(Key, Vec<String>, Vec<Vec<String>>),
+    GetSnapshotRecord(Key, String),
+    GetKYCRecord(Key, PublicKey),
+    GetAllowanceCap(Key),
+    GetAdmin(Key),
+    ProposeAdmin(PublicKey),
+    AcceptAdmin(Key),
+    GrantRole(String, PublicKey),
+    RevokeRole(String, PublicKey),
+    RegisterSwapHash(Key),
+    SetMultisigConfig(Vec<PublicKey>, U512),
+    ProposeAction(String, Vec<String>),
+    ApproveProposal(U512),
+    ExecuteProposal(U512),
+    CancelProposal(U512),
+    GetProposal(Key, U512),
+    GetPendingProposals(Key),
+    PauseSwap,
+    UnpauseSwap,
+    SetClaimWindow(u64, u64),
+    SweepUnclaimed(Key),
+    GetReserveStatus(Key),
+    GetStats(Key),
+    GetReceipt(Key, String),
+    GetReceiptsByAccount(Key, PublicKey),
+    GetEvents(Key, U512, U512),
+    SetKYCTier(U512, U512, U512, u64, bool),
+    GetKYCTier(Key, U512),
+    RenewKYC(PublicKey, u64),
+    GetExpiringKYCRecords(Key, u64, U512, U512),
+    RevokeKYC(PublicKey),
+    FreezeAccount(PublicKey),
+    UnfreezeAccount(PublicKey),
+    AddToDenylist(Vec<String>),
+    RemoveFromDenylist(String),
+    IsDenylisted(Key, String),
+    ReplaceSnapshotMerkleRoot(String, U512),
+    ClaimPending(Key),
 }
 
 fn get_contract_ref() -> ContractRef {
@@ -71,6 +210,24 @@ impl Api {
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
                 Api::InsertSnapshotRecord(ver1_address, amount)
             }
+            method_names::proxy::METHOD_INSERT_SNAPSHOT_RECORDS => {
+                let ver1_addresses: Vec<String> = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let amounts: Vec<U512> = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                Api::InsertSnapshotRecords(ver1_addresses, amounts)
+            }
+            method_names::proxy::METHOD_AMEND_SNAPSHOT_RECORD => {
+                let ver1_address: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let amount: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                Api::AmendSnapshotRecord(ver1_address, amount)
+            }
             method_names::proxy::METHOD_INSERT_KYC_DATA => {
                 let new_mainnet_address: PublicKey = runtime::get_arg(1)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
@@ -78,8 +235,12 @@ impl Api {
                 let kyc_level: U512 = runtime::get_arg(2)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let expires_at: u64 = match runtime::get_arg(3) {
+                    Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+                    None => 0,
+                };
 
-                Api::InsertKYCData(new_mainnet_address, kyc_level)
+                Api::InsertKYCData(new_mainnet_address, kyc_level, expires_at)
             }
             method_names::proxy::METHOD_UPDATE_KYC_LEVEL => {
                 let new_mainnet_address: PublicKey = runtime::get_arg(1)
@@ -88,7 +249,10 @@ impl Api {
                 let kyc_level: U512 = runtime::get_arg(2)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
-                Api::UpdateKYCLevel(new_mainnet_address, kyc_level)
+                let expires_at: Option<u64> = runtime::get_arg(3)
+                    .map(|val| val.unwrap_or_revert_with(ApiError::InvalidArgument));
+
+                Api::UpdateKYCLevel(new_mainnet_address, kyc_level, expires_at)
             }
             method_names::proxy::METHOD_GET_TOKEN => {
                 let contract_hash: Key = runtime::get_arg(1)
@@ -97,102 +261,1040 @@ impl Api {
                 let ver1_pubkey: Vec<String> = runtime::get_arg(2)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let message: Vec<String> = runtime::get_arg(3)
+                let signature: Vec<String> = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let allow_partial: bool = match runtime::get_arg(4) {
+                    Some(val) => val.unwrap_or_revert_with(ApiError::InvalidArgument),
+                    None => false,
+                };
+
+                Api::GetToken(contract_hash, ver1_pubkey, signature, allow_partial)
+            }
+            method_names::proxy::METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let message: Vec<String> = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let signature: Vec<String> = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetTokenWithSignedMessage(contract_hash, message, signature)
+            }
+            method_names::proxy::METHOD_INSERT_SNAPSHOT_MERKLE_ROOT => {
+                let merkle_root: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let total_amount: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::InsertSnapshotMerkleRoot(merkle_root, total_amount)
+            }
+            method_names::proxy::METHOD_GET_TOKEN_WITH_MERKLE_PROOF => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let ver1_pubkey: Vec<String> = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let signature: Vec<String> = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let leaf_index: Vec<u64> = runtime::get_arg(4)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let prev_balance: Vec<U512> = runtime::get_arg(5)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let merkle_proof: Vec<Vec<String>> = runtime::get_arg(6)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetTokenWithMerkleProof(
+                    contract_hash,
+                    ver1_pubkey,
+                    signature,
+                    leaf_index,
+                    prev_balance,
+                    merkle_proof,
+                )
+            }
+            method_names::proxy::METHOD_GET_TOKEN_WITH_MULTISIG => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let redeem_script: Vec<String> = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let signature: Vec<Vec<String>> = runtime::get_arg(3)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let signature: Vec<String> = runtime::get_arg(4)
+
+                Api::GetTokenWithMultisig(contract_hash, redeem_script, signature)
+            }
+            method_names::proxy::METHOD_CLAIM_PENDING => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ClaimPending(contract_hash)
+            }
+            method_names::proxy::METHOD_GET_SNAPSHOT_RECORD => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let ver1_address: String = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetSnapshotRecord(contract_hash, ver1_address)
+            }
+            method_names::proxy::METHOD_GET_KYC_RECORD => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let mainnet_address: PublicKey = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetKYCRecord(contract_hash, mainnet_address)
+            }
+            method_names::proxy::METHOD_GET_ALLOWANCE_CAP => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetAllowanceCap(contract_hash)
+            }
+            method_names::proxy::METHOD_GET_ADMIN => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetAdmin(contract_hash)
+            }
+            method_names::proxy::METHOD_PROPOSE_ADMIN => {
+                let new_admin: PublicKey = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ProposeAdmin(new_admin)
+            }
+            method_names::proxy::METHOD_ACCEPT_ADMIN => {
+                // The new admin doesn't have the named key of the contract yet
+                let contract_hash: Key = runtime::get_arg(1)
                     .unwrap_or_revert_with(ApiError::MissingArgument)
                     .unwrap_or_revert_with(ApiError::InvalidArgument);
 
-                Api::GetToken(contract_hash, ver1_pubkey, message, signature)
+                Api::AcceptAdmin(contract_hash)
+            }
+            method_names::proxy::METHOD_GRANT_ROLE => {
+                let role: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let account: PublicKey = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GrantRole(role, account)
+            }
+            method_names::proxy::METHOD_REVOKE_ROLE => {
+                let role: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let account: PublicKey = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::RevokeRole(role, account)
+            }
+            method_names::proxy::METHOD_REGISTER_SWAP_HASH => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::RegisterSwapHash(contract_hash)
+            }
+            method_names::proxy::METHOD_SET_MULTISIG_CONFIG => {
+                let signers: Vec<PublicKey> = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let threshold: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::SetMultisigConfig(signers, threshold)
+            }
+            method_names::proxy::METHOD_PROPOSE_ACTION => {
+                let action: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let args: Vec<String> = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ProposeAction(action, args)
+            }
+            method_names::proxy::METHOD_APPROVE_PROPOSAL => {
+                let proposal_id: U512 = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ApproveProposal(proposal_id)
+            }
+            method_names::proxy::METHOD_EXECUTE_PROPOSAL => {
+                let proposal_id: U512 = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ExecuteProposal(proposal_id)
+            }
+            method_names::proxy::METHOD_CANCEL_PROPOSAL => {
+                let proposal_id: U512 = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::CancelProposal(proposal_id)
+            }
+            method_names::proxy::METHOD_GET_PROPOSAL => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let proposal_id: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetProposal(contract_hash, proposal_id)
+            }
+            method_names::proxy::METHOD_GET_PENDING_PROPOSALS => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetPendingProposals(contract_hash)
+            }
+            method_names::proxy::METHOD_PAUSE_SWAP => Api::PauseSwap,
+            method_names::proxy::METHOD_UNPAUSE_SWAP => Api::UnpauseSwap,
+            method_names::proxy::METHOD_SET_CLAIM_WINDOW => {
+                let claim_start: u64 = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let claim_end: u64 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::SetClaimWindow(claim_start, claim_end)
+            }
+            method_names::proxy::METHOD_SET_KYC_TIER => {
+                let kyc_level: U512 = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let cap: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let period_limit: U512 = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let period: u64 = runtime::get_arg(4)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let enabled: bool = runtime::get_arg(5)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::SetKYCTier(kyc_level, cap, period_limit, period, enabled)
+            }
+            method_names::proxy::METHOD_GET_KYC_TIER => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let kyc_level: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetKYCTier(contract_hash, kyc_level)
+            }
+            method_names::proxy::METHOD_RENEW_KYC => {
+                let mainnet_address: PublicKey = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let expires_at: u64 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::RenewKYC(mainnet_address, expires_at)
+            }
+            method_names::proxy::METHOD_GET_EXPIRING_KYC_RECORDS => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let expires_before: u64 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let from: U512 = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let limit: U512 = runtime::get_arg(4)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetExpiringKYCRecords(contract_hash, expires_before, from, limit)
+            }
+            method_names::proxy::METHOD_REVOKE_KYC => {
+                let mainnet_address: PublicKey = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::RevokeKYC(mainnet_address)
+            }
+            method_names::proxy::METHOD_FREEZE_ACCOUNT => {
+                let mainnet_address: PublicKey = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::FreezeAccount(mainnet_address)
+            }
+            method_names::proxy::METHOD_UNFREEZE_ACCOUNT => {
+                let mainnet_address: PublicKey = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::UnfreezeAccount(mainnet_address)
+            }
+            method_names::proxy::METHOD_ADD_TO_DENYLIST => {
+                let ver1_addresses: Vec<String> = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::AddToDenylist(ver1_addresses)
+            }
+            method_names::proxy::METHOD_REMOVE_FROM_DENYLIST => {
+                let ver1_address: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::RemoveFromDenylist(ver1_address)
+            }
+            method_names::proxy::METHOD_IS_DENYLISTED => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let ver1_address: String = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::IsDenylisted(contract_hash, ver1_address)
+            }
+            method_names::proxy::METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT => {
+                let merkle_root: String = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let total_amount: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::ReplaceSnapshotMerkleRoot(merkle_root, total_amount)
+            }
+            method_names::proxy::METHOD_SWEEP_UNCLAIMED => {
+                let target: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::SweepUnclaimed(target)
+            }
+            method_names::proxy::METHOD_GET_RESERVE_STATUS => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetReserveStatus(contract_hash)
+            }
+            method_names::proxy::METHOD_GET_STATS => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetStats(contract_hash)
+            }
+            method_names::proxy::METHOD_GET_RECEIPT => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let ver1_address: String = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetReceipt(contract_hash, ver1_address)
+            }
+            method_names::proxy::METHOD_GET_RECEIPTS_BY_ACCOUNT => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let mainnet_address: PublicKey = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetReceiptsByAccount(contract_hash, mainnet_address)
+            }
+            method_names::proxy::METHOD_GET_EVENTS => {
+                let contract_hash: Key = runtime::get_arg(1)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let from: U512 = runtime::get_arg(2)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let limit: U512 = runtime::get_arg(3)
+                    .unwrap_or_revert_with(ApiError::MissingArgument)
+                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+                Api::GetEvents(contract_hash, from, limit)
             }
             _ => runtime::revert(Error::UnknownProxyApi),
         }
     }
 
-    pub fn invoke(&self) {
-        match self {
-            Self::InsertKYCAllowanceCap(allowance_cap) => {
-                let swap_ref = get_contract_ref();
+    pub fn invoke(&self) {
+        match self {
+            Self::InsertKYCAllowanceCap(allowance_cap) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_INSERT_KYC_ALLOWANCE_CAP,
+                        *allowance_cap,
+                    ),
+                )
+            }
+            Self::InsertSnapshotRecord(ver1_address, amount) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref.clone(),
+                    (
+                        method_names::proxy::METHOD_INSERT_SNAPSHOT_RECORD,
+                        ver1_address.clone(),
+                        *amount,
+                    ),
+                );
+
+                let contract_purse: URef = runtime::call_contract::<_, URef>(
+                    swap_ref,
+                    (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
+                );
+
+                let transfer_res = system::transfer_from_purse_to_purse(
+                    account::get_main_purse(),
+                    contract_purse,
+                    *amount,
+                );
+
+                match transfer_res {
+                    Ok(_) => (),
+                    Err(err) => runtime::revert(err),
+                }
+            }
+            Self::InsertSnapshotRecords(ver1_addresses, amounts) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref.clone(),
+                    (
+                        method_names::proxy::METHOD_INSERT_SNAPSHOT_RECORDS,
+                        ver1_addresses.clone(),
+                        amounts.clone(),
+                    ),
+                );
+
+                // Fund the purse once with the sum of the whole batch
+                let total_amount = amounts
+                    .iter()
+                    .fold(U512::from(0), |total, amount| total + *amount);
+
+                let contract_purse: URef = runtime::call_contract::<_, URef>(
+                    swap_ref,
+                    (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
+                );
+
+                let transfer_res = system::transfer_from_purse_to_purse(
+                    account::get_main_purse(),
+                    contract_purse,
+                    total_amount,
+                );
+
+                match transfer_res {
+                    Ok(_) => (),
+                    Err(err) => runtime::revert(err),
+                }
+            }
+            Self::AmendSnapshotRecord(ver1_address, amount) => {
+                let swap_ref = get_contract_ref();
+
+                // Decrease is refunded by the contract itself,
+                // and increase should be funded from here.
+                let prev_amount: U512 = runtime::call_contract::<_, U512>(
+                    swap_ref.clone(),
+                    (
+                        method_names::proxy::METHOD_AMEND_SNAPSHOT_RECORD,
+                        ver1_address.clone(),
+                        *amount,
+                    ),
+                );
+
+                if *amount > prev_amount {
+                    let contract_purse: URef = runtime::call_contract::<_, URef>(
+                        swap_ref,
+                        (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
+                    );
+
+                    let transfer_res = system::transfer_from_purse_to_purse(
+                        account::get_main_purse(),
+                        contract_purse,
+                        *amount - prev_amount,
+                    );
+
+                    match transfer_res {
+                        Ok(_) => (),
+                        Err(err) => runtime::revert(err),
+                    }
+                }
+            }
+            Self::InsertKYCData(new_mainnet_address, kyc_level, expires_at) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_INSERT_KYC_DATA,
+                        *new_mainnet_address,
+                        *kyc_level,
+                        *expires_at,
+                    ),
+                );
+
+                let transfer_res: TransferResult = system::transfer_to_account(
+                    *new_mainnet_address,
+                    U512::from(BIGSUN_TO_HDAC / 10_u64), // 0.1 Hdac
+                );
+
+                if let Err(err) = transfer_res {
+                    runtime::revert(err);
+                }
+            }
+            Self::UpdateKYCLevel(new_mainnet_address, kyc_level, expires_at) => {
+                let swap_ref = get_contract_ref();
+                match expires_at {
+                    Some(expires_at) => runtime::call_contract(
+                        swap_ref,
+                        (
+                            method_names::proxy::METHOD_UPDATE_KYC_LEVEL,
+                            *new_mainnet_address,
+                            *kyc_level,
+                            *expires_at,
+                        ),
+                    ),
+                    None => runtime::call_contract(
+                        swap_ref,
+                        (
+                            method_names::proxy::METHOD_UPDATE_KYC_LEVEL,
+                            *new_mainnet_address,
+                            *kyc_level,
+                        ),
+                    ),
+                }
+            }
+            Self::GetToken(swap_contract_hash, ver1_pubkey_arr, signature_arr, allow_partial) => {
+                let contract_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+
                 runtime::call_contract(
-                    swap_ref,
+                    contract_ref,
                     (
-                        method_names::proxy::METHOD_INSERT_KYC_ALLOWANCE_CAP,
-                        *allowance_cap,
+                        method_names::proxy::METHOD_GET_TOKEN,
+                        ver1_pubkey_arr.clone(),
+                        signature_arr.clone(),
+                        *allow_partial,
                     ),
                 )
             }
-            Self::InsertSnapshotRecord(ver1_address, amount) => {
-                let swap_ref = get_contract_ref();
-                runtime::call_contract::<_, ()>(
-                    swap_ref.clone(),
+            Self::GetTokenWithSignedMessage(swap_contract_hash, message_arr, signature_arr) => {
+                let contract_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+
+                runtime::call_contract(
+                    contract_ref,
                     (
-                        method_names::proxy::METHOD_INSERT_SNAPSHOT_RECORD,
-                        ver1_address.clone(),
-                        *amount,
+                        method_names::proxy::METHOD_GET_TOKEN_WITH_SIGNED_MESSAGE,
+                        message_arr.clone(),
+                        signature_arr.clone(),
                     ),
-                );
+                )
+            }
+            Self::InsertSnapshotMerkleRoot(merkle_root, total_amount) => {
+                let swap_ref = get_contract_ref();
 
+                // Whole snapshot is funded at once with the sum of every leaf,
+                // before the contract checks the purse covers it
                 let contract_purse: URef = runtime::call_contract::<_, URef>(
-                    swap_ref,
+                    swap_ref.clone(),
                     (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
                 );
 
                 let transfer_res = system::transfer_from_purse_to_purse(
                     account::get_main_purse(),
                     contract_purse,
-                    *amount,
+                    *total_amount,
                 );
 
                 match transfer_res {
                     Ok(_) => (),
                     Err(err) => runtime::revert(err),
                 }
-            }
-            Self::InsertKYCData(new_mainnet_address, kyc_level) => {
-                let swap_ref = get_contract_ref();
+
                 runtime::call_contract::<_, ()>(
                     swap_ref,
                     (
-                        method_names::proxy::METHOD_INSERT_KYC_DATA,
-                        *new_mainnet_address,
-                        *kyc_level,
+                        method_names::proxy::METHOD_INSERT_SNAPSHOT_MERKLE_ROOT,
+                        merkle_root.clone(),
+                        *total_amount,
                     ),
                 );
+            }
+            Self::ReplaceSnapshotMerkleRoot(merkle_root, total_amount) => {
+                let swap_ref = get_contract_ref();
 
-                let transfer_res: TransferResult = system::transfer_to_account(
-                    *new_mainnet_address,
-                    U512::from(BIGSUN_TO_HDAC / 10_u64), // 0.1 Hdac
+                // Decrease is refunded by the contract itself,
+                // and increase should be funded from here.
+                let prev_total: U512 = runtime::call_contract::<_, U512>(
+                    swap_ref.clone(),
+                    (
+                        method_names::proxy::METHOD_REPLACE_SNAPSHOT_MERKLE_ROOT,
+                        merkle_root.clone(),
+                        *total_amount,
+                    ),
                 );
 
-                if let Err(err) = transfer_res {
-                    runtime::revert(err);
+                if *total_amount > prev_total {
+                    let contract_purse: URef = runtime::call_contract::<_, URef>(
+                        swap_ref,
+                        (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
+                    );
+
+                    let transfer_res = system::transfer_from_purse_to_purse(
+                        account::get_main_purse(),
+                        contract_purse,
+                        *total_amount - prev_total,
+                    );
+
+                    match transfer_res {
+                        Ok(_) => (),
+                        Err(err) => runtime::revert(err),
+                    }
                 }
             }
-            Self::UpdateKYCLevel(new_mainnet_address, kyc_level) => {
-                let swap_ref = get_contract_ref();
+            Self::GetTokenWithMerkleProof(
+                swap_contract_hash,
+                ver1_pubkey_arr,
+                signature_arr,
+                leaf_index_arr,
+                prev_balance_arr,
+                merkle_proof_arr,
+            ) => {
+                let contract_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+
                 runtime::call_contract(
-                    swap_ref,
+                    contract_ref,
                     (
-                        method_names::proxy::METHOD_UPDATE_KYC_LEVEL,
-                        *new_mainnet_address,
-                        *kyc_level,
+                        method_names::proxy::METHOD_GET_TOKEN_WITH_MERKLE_PROOF,
+                        ver1_pubkey_arr.clone(),
+                        signature_arr.clone(),
+                        leaf_index_arr.clone(),
+                        prev_balance_arr.clone(),
+                        merkle_proof_arr.clone(),
                     ),
                 )
             }
-            Self::GetToken(swap_contract_hash, ver1_pubkey_arr, message_arr, signature_arr) => {
+            Self::GetTokenWithMultisig(swap_contract_hash, redeem_script_arr, signature_arr) => {
                 let contract_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
 
                 runtime::call_contract(
                     contract_ref,
                     (
-                        method_names::proxy::METHOD_GET_TOKEN,
-                        ver1_pubkey_arr.clone(),
-                        message_arr.clone(),
+                        method_names::proxy::METHOD_GET_TOKEN_WITH_MULTISIG,
+                        redeem_script_arr.clone(),
                         signature_arr.clone(),
                     ),
                 )
             }
+            Self::ClaimPending(swap_contract_hash) => {
+                let contract_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+
+                runtime::call_contract(contract_ref, (method_names::proxy::METHOD_CLAIM_PENDING,))
+            }
+            Self::GetSnapshotRecord(swap_contract_hash, ver1_address) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let snapshot_record: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_GET_SNAPSHOT_RECORD,
+                        ver1_address.clone(),
+                    ),
+                );
+
+                let ret = CLValue::from_t(snapshot_record).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetKYCRecord(swap_contract_hash, mainnet_address) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let kyc_record: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_KYC_RECORD, *mainnet_address),
+                );
+
+                let ret = CLValue::from_t(kyc_record).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetAllowanceCap(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let allowance_cap: U512 = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_ALLOWANCE_CAP,),
+                );
+
+                let ret = CLValue::from_t(allowance_cap).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetAdmin(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let admin: PublicKey =
+                    runtime::call_contract(swap_ref, (method_names::proxy::METHOD_GET_ADMIN,));
+
+                let ret = CLValue::from_t(admin).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::ProposeAdmin(new_admin) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_PROPOSE_ADMIN, *new_admin),
+                );
+            }
+            Self::AcceptAdmin(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_ACCEPT_ADMIN,),
+                );
+
+                // Admin methods find the contract by this named key of the admin account
+                runtime::put_key(method_names::proxy::NAME_SWAP_HASH, *swap_contract_hash);
+            }
+            Self::GrantRole(role, account) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_GRANT_ROLE,
+                        role.clone(),
+                        *account,
+                    ),
+                );
+            }
+            Self::RevokeRole(role, account) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_REVOKE_ROLE,
+                        role.clone(),
+                        *account,
+                    ),
+                );
+            }
+            Self::RegisterSwapHash(swap_contract_hash) => {
+                // Operators find the contract by this named key, as the admin does
+                runtime::put_key(method_names::proxy::NAME_SWAP_HASH, *swap_contract_hash);
+            }
+            Self::SetMultisigConfig(signers, threshold) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_SET_MULTISIG_CONFIG,
+                        signers.clone(),
+                        *threshold,
+                    ),
+                );
+            }
+            Self::ProposeAction(action, args) => {
+                let swap_ref = get_contract_ref();
+                let proposal_id: U512 = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_PROPOSE_ACTION,
+                        action.clone(),
+                        args.clone(),
+                    ),
+                );
+
+                let ret = CLValue::from_t(proposal_id).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::ApproveProposal(proposal_id) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_APPROVE_PROPOSAL, *proposal_id),
+                );
+            }
+            Self::ExecuteProposal(proposal_id) => {
+                let swap_ref = get_contract_ref();
+                let required_funds: U512 = runtime::call_contract(
+                    swap_ref.clone(),
+                    (method_names::proxy::METHOD_EXECUTE_PROPOSAL, *proposal_id),
+                );
+
+                // Increase of an amended snapshot record or a replaced merkle root
+                // is funded by the executor
+                if required_funds > U512::from(0) {
+                    let contract_purse: URef = runtime::call_contract::<_, URef>(
+                        swap_ref,
+                        (method_names::swap::METHOD_GET_CONTRACT_PURSE,),
+                    );
+
+                    let transfer_res = system::transfer_from_purse_to_purse(
+                        account::get_main_purse(),
+                        contract_purse,
+                        required_funds,
+                    );
+
+                    match transfer_res {
+                        Ok(_) => (),
+                        Err(err) => runtime::revert(err),
+                    }
+                }
+            }
+            Self::CancelProposal(proposal_id) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_CANCEL_PROPOSAL, *proposal_id),
+                );
+            }
+            Self::GetProposal(swap_contract_hash, proposal_id) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let proposal: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_PROPOSAL, *proposal_id),
+                );
+
+                let ret = CLValue::from_t(proposal).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetPendingProposals(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let pending_proposals: Vec<U512> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_PENDING_PROPOSALS,),
+                );
+
+                let ret = CLValue::from_t(pending_proposals).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::PauseSwap => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_PAUSE_SWAP,),
+                );
+            }
+            Self::UnpauseSwap => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_UNPAUSE_SWAP,),
+                );
+            }
+            Self::SetClaimWindow(claim_start, claim_end) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_SET_CLAIM_WINDOW,
+                        *claim_start,
+                        *claim_end,
+                    ),
+                );
+            }
+            Self::SweepUnclaimed(target) => {
+                let swap_ref = get_contract_ref();
+                let swept_amount: U512 = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_SWEEP_UNCLAIMED, *target),
+                );
+
+                let ret = CLValue::from_t(swept_amount).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetReserveStatus(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let reserve_status: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_RESERVE_STATUS,),
+                );
+
+                let ret = CLValue::from_t(reserve_status).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetStats(swap_contract_hash) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let stats: BTreeMap<String, String> =
+                    runtime::call_contract(swap_ref, (method_names::proxy::METHOD_GET_STATS,));
+
+                let ret = CLValue::from_t(stats).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::SetKYCTier(kyc_level, cap, period_limit, period, enabled) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_SET_KYC_TIER,
+                        *kyc_level,
+                        *cap,
+                        *period_limit,
+                        *period,
+                        *enabled,
+                    ),
+                );
+            }
+            Self::GetKYCTier(swap_contract_hash, kyc_level) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let tier: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_KYC_TIER, *kyc_level),
+                );
+
+                let ret = CLValue::from_t(tier).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::RenewKYC(mainnet_address, expires_at) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_RENEW_KYC,
+                        *mainnet_address,
+                        *expires_at,
+                    ),
+                );
+            }
+            Self::GetExpiringKYCRecords(swap_contract_hash, expires_before, from, limit) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let records: Vec<BTreeMap<String, String>> = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_GET_EXPIRING_KYC_RECORDS,
+                        *expires_before,
+                        *from,
+                        *limit,
+                    ),
+                );
+
+                let ret = CLValue::from_t(records).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::RevokeKYC(mainnet_address) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_REVOKE_KYC, *mainnet_address),
+                );
+            }
+            Self::FreezeAccount(mainnet_address) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (method_names::proxy::METHOD_FREEZE_ACCOUNT, *mainnet_address),
+                );
+            }
+            Self::UnfreezeAccount(mainnet_address) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_UNFREEZE_ACCOUNT,
+                        *mainnet_address,
+                    ),
+                );
+            }
+            Self::AddToDenylist(ver1_addresses) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_ADD_TO_DENYLIST,
+                        ver1_addresses.clone(),
+                    ),
+                );
+            }
+            Self::RemoveFromDenylist(ver1_address) => {
+                let swap_ref = get_contract_ref();
+                runtime::call_contract::<_, ()>(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_REMOVE_FROM_DENYLIST,
+                        ver1_address.clone(),
+                    ),
+                );
+            }
+            Self::IsDenylisted(swap_contract_hash, ver1_address) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let is_denylisted: bool = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_IS_DENYLISTED,
+                        ver1_address.clone(),
+                    ),
+                );
+
+                let ret = CLValue::from_t(is_denylisted).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetReceipt(swap_contract_hash, ver1_address) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let receipt: BTreeMap<String, String> = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_GET_RECEIPT,
+                        ver1_address.clone(),
+                    ),
+                );
+
+                let ret = CLValue::from_t(receipt).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetReceiptsByAccount(swap_contract_hash, mainnet_address) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let receipts: Vec<BTreeMap<String, String>> = runtime::call_contract(
+                    swap_ref,
+                    (
+                        method_names::proxy::METHOD_GET_RECEIPTS_BY_ACCOUNT,
+                        *mainnet_address,
+                    ),
+                );
+
+                let ret = CLValue::from_t(receipts).unwrap_or_revert();
+                runtime::ret(ret)
+            }
+            Self::GetEvents(swap_contract_hash, from, limit) => {
+                let swap_ref = swap_contract_hash.to_contract_ref().unwrap_or_revert();
+                let events: Vec<BTreeMap<String, String>> = runtime::call_contract(
+                    swap_ref,
+                    (method_names::proxy::METHOD_GET_EVENTS, *from, *limit),
+                );
+
+                let ret = CLValue::from_t(events).unwrap_or_revert();
+                runtime::ret(ret)
+            }
         }
     }
 }
@@ -11,6 +11,8 @@ use engine_test_support::{
     internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
     DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
+use secp256k1::{Message, SecretKey};
+use sha2::{Digest, Sha256};
 use types::{account::PublicKey, ApiError, CLValue, Key, U512};
 
 const CONTRACT_POS_VOTE: &str = "swap_install.wasm";
@@ -21,13 +23,19 @@ const ACCOUNT_1_PUBKEY: PublicKey = PublicKey::ed25519_from([2u8; 32]);
 
 const GENESIS_VALIDATOR_STAKE: u64 = 5u64 * BIGSUN_TO_HDAC;
 
-const VER1_ADDRESS: &str = "HLkXSESzSaDZgU25CQrmxkjRayKfs5xBFK";
-const VER1_PUBKEY: &str = "02c4ef70543e18889167ca67c8aa28c1d4c259e89cb34483a8ed6cfd3a03e8246b";
-const VER1_MESSAGE_HASHED: &str =
-    "69046d44e3d75d48436377626372a44a5066966b5d72c00b67769c1cc6a8619a";
-const VER1_SIGNATURE: &str =
-    "24899366fd3d5dfe6740df1e5f467a53f1a3aaafce26d8df1497a925c55b5c266339a95fe6\
-                              507bd611b0e3b6e74e3bb7f19eeb1165615e5cebe7f40e5765bc41";
+// Test only ver1 key. Swap message contains the contract hash, so it is signed in each test.
+const VER1_SECRET_KEY: [u8; 32] = [7u8; 32];
+const VER1_ADDRESS: &str = "HMT6ZBkkjUFTguoycUaLuG1pjm83s8dfi2";
+const VER1_PUBKEY: &str = "02989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f";
+
+// Uncompressed public key of the same key, which has its own address
+const VER1_UNCOMPRESSED_PUBKEY: &str = "04989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f631f4d05b3ae518776ee08755a7703e64b2ebc32547504de0b55a142d4ecdf80";
+const VER1_UNCOMPRESSED_ADDRESS: &str = "HKar8qXrhJsSTF4gp5GzznvXspPQHZuSh6";
+
+// 2-of-2 multisig of the test only ver1 key above and the one below, and its P2SH address
+const VER1_MULTISIG_SECRET_KEY: [u8; 32] = [8u8; 32];
+const VER1_REDEEM_SCRIPT: &str = "522102989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f2103f991f944d1e1954a7fc8b9bf62e0d78f015f4c07762d505e20e6c45260a3661b52ae";
+const VER1_P2SH_ADDRESS: &str = "4S17r7hgoXqydJgKV1DD4rqWnneiHgRrSM";
 
 const VER1_ADDRESS_2: &str = "H9EtjvP88K51nTSevyNW2p9VkSbuzhwgWQ";
 // const VER1_PUBKEY_2: &str = "02fce4c49d848d3389f71d7dfd28b0a7fea9861e9b0343fe2572e31178d116f35f";
@@ -69,6 +77,114 @@ fn get_swap_stored_hash(builder: &InMemoryWasmTestBuilder) -> Key {
         .expect("should get swap key")
 }
 
+fn swap_message(
+    builder: &InMemoryWasmTestBuilder,
+    mainnet_address: PublicKey,
+    ver1_address: &str,
+) -> String {
+    let contract_hash = get_swap_stored_hash(builder)
+        .into_hash()
+        .expect("should be hash");
+    format!(
+        "Hdac token swap:{}:{}:{}",
+        hex::encode(contract_hash),
+        to_hex_string(mainnet_address),
+        ver1_address
+    )
+}
+
+fn sign_swap_message(
+    builder: &InMemoryWasmTestBuilder,
+    mainnet_address: PublicKey,
+    ver1_address: &str,
+) -> String {
+    let message = swap_message(builder, mainnet_address, ver1_address);
+    sign_with_ver1_key(&VER1_SECRET_KEY, &message)
+}
+
+fn sign_with_ver1_key(secret_key: &[u8; 32], message: &str) -> String {
+    let digest = Sha256::digest(message.as_bytes());
+    let (signature, _) = secp256k1::sign(
+        &Message::parse_slice(&digest).expect("should parse digest"),
+        &SecretKey::parse(secret_key).expect("should parse secret key"),
+    );
+
+    hex::encode(&signature.serialize()[..])
+}
+
+// Signs as "signmessage" of ver1 does, and returns base64 of the compact signature
+fn sign_ver1_message(message: &str) -> String {
+    const MAGIC: &str = "Hdac Signed Message:\n";
+
+    // Both are shorter than 0xfd, so the length prefix is a single byte
+    let mut payload: Vec<u8> = Vec::new();
+    payload.push(MAGIC.len() as u8);
+    payload.extend(MAGIC.as_bytes());
+    payload.push(message.len() as u8);
+    payload.extend(message.as_bytes());
+
+    let digest = Sha256::digest(&Sha256::digest(&payload));
+    let (signature, recovery_id) = secp256k1::sign(
+        &Message::parse_slice(&digest).expect("should parse digest"),
+        &SecretKey::parse(&VER1_SECRET_KEY).expect("should parse secret key"),
+    );
+
+    // Header 31 ~ 34 for the compressed public key
+    let mut compact_signature: Vec<u8> = vec![31 + recovery_id.serialize()];
+    compact_signature.extend(&signature.serialize()[..]);
+    base64::encode(&compact_signature)
+}
+
+fn get_swap_contract_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let contract_ref = get_swap_stored_hash(builder);
+    let contract_purse = match builder
+        .query(None, contract_ref, &[])
+        .expect("should query swap contract")
+    {
+        StoredValue::Contract(contract) => *contract
+            .named_keys()
+            .get("swap_contract_purse")
+            .expect("should have contract purse")
+            .as_uref()
+            .expect("should be uref"),
+        _ => panic!("should get a contract"),
+    };
+
+    builder.get_purse_balance(contract_purse)
+}
+
+fn get_admin(builder: &InMemoryWasmTestBuilder) -> PublicKey {
+    let contract_ref = get_swap_stored_hash(builder);
+    CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["admin"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully")
+}
+
+fn get_stored_u512(builder: &InMemoryWasmTestBuilder, key_name: &str) -> U512 {
+    let contract_ref = get_swap_stored_hash(builder);
+    CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[key_name],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully")
+}
+
 fn to_hex_string(address: PublicKey) -> String {
     let bytes = address.value();
     let mut ret = String::with_capacity(64);
@@ -79,6 +195,34 @@ fn to_hex_string(address: PublicKey) -> String {
     ret
 }
 
+// Same hashes as the contract: leaf = SHA256(0x00 || index || address || balance),
+// node = SHA256(0x01 || left || right)
+fn merkle_leaf_hash(leaf_index: u64, ver1_address: &str, prev_balance: U512) -> [u8; 32] {
+    let mut balance_bytes: [u8; 64] = [0u8; 64];
+    prev_balance.to_big_endian(&mut balance_bytes);
+
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input([0u8]);
+    sha256hasher.input(leaf_index.to_be_bytes());
+    sha256hasher.input(ver1_address.as_bytes());
+    sha256hasher.input(&balance_bytes[..]);
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(sha256hasher.result().as_slice());
+    res
+}
+
+fn merkle_node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut sha256hasher = Sha256::new();
+    sha256hasher.input([1u8]);
+    sha256hasher.input(left);
+    sha256hasher.input(right);
+
+    let mut res: [u8; 32] = [0u8; 32];
+    res.copy_from_slice(sha256hasher.result().as_slice());
+    res
+}
+
 #[ignore]
 #[test]
 fn should_run_insert_update_info_and_swap_step() {
@@ -221,8 +365,7 @@ fn should_run_insert_update_info_and_swap_step() {
             "get_token",
             contract_ref,
             vec![VER1_PUBKEY],
-            vec![VER1_MESSAGE_HASHED],
-            vec![VER1_SIGNATURE],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
         ),
     )
     .build();
@@ -289,8 +432,7 @@ fn should_run_insert_update_info_and_swap_step() {
             "get_token",
             contract_ref,
             vec![VER1_PUBKEY],
-            vec![VER1_MESSAGE_HASHED],
-            vec![VER1_SIGNATURE],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
         ),
     )
     .build();
@@ -343,8 +485,7 @@ fn should_run_insert_update_info_and_swap_step() {
             "get_token",
             contract_ref,
             vec![VER1_PUBKEY],
-            vec![VER1_MESSAGE_HASHED],
-            vec![VER1_SIGNATURE],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
         ),
     )
     .build();
@@ -477,8 +618,7 @@ fn should_fail_swaprequest_if_kyc_is_not_inserted() {
             "get_token",
             contract_ref,
             vec![VER1_PUBKEY],
-            vec![VER1_MESSAGE_HASHED],
-            vec![VER1_SIGNATURE],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
         ),
     )
     .build();
@@ -584,8 +724,7 @@ fn should_fail_swaprequest_if_there_is_no_snapshot() {
             "get_token",
             contract_ref,
             vec![VER1_PUBKEY],
-            vec![VER1_MESSAGE_HASHED],
-            vec![VER1_SIGNATURE],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
         ),
     )
     .build();
@@ -610,3 +749,4141 @@ fn should_fail_swaprequest_if_there_is_no_snapshot() {
         U512::from(0),
     );
 }
+
+#[ignore]
+#[test]
+fn should_claim_merkle_leaf_only_once() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    // Two leaves tree. The proof of the leaf 0 is the leaf 1 itself.
+    let leaf_0 = merkle_leaf_hash(0, VER1_ADDRESS, U512::from(VER1_AMOUNT_1));
+    let leaf_1 = merkle_leaf_hash(1, VER1_ADDRESS_2, U512::from(VER1_AMOUNT_2));
+    let merkle_root = merkle_node_hash(&leaf_0, &leaf_1);
+
+    println!("2. Insert snapshot merkle root, and register and upgrade KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_merkle_root",
+                hex::encode(merkle_root),
+                U512::from(VER1_AMOUNT_1 + VER1_AMOUNT_2),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token with merkle proof");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_merkle_proof",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+            vec![0u64],
+            vec![U512::from(VER1_AMOUNT_1)],
+            vec![vec![hex::encode(leaf_1)]],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    assert_eq!(
+        get_stored_u512(&builder, "snapshot_merkle_claimed_leaves"),
+        U512::from(1)
+    );
+
+    let stats: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["swap_stats"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(stats.get("num_of_snapshot_records"), None);
+    assert_eq!(
+        stats.get("merkle_snapshot_amount").unwrap(),
+        &(VER1_AMOUNT_1 + VER1_AMOUNT_2).to_string()
+    );
+    assert_eq!(stats.get("num_of_claimed_merkle_leaves").unwrap(), "1");
+    assert_eq!(stats.get("num_of_claimed_records").unwrap(), "1");
+    let result = builder.finish();
+
+    println!("4. Get token with the same leaf again. Should fail");
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_merkle_proof",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature],
+            vec![0u64],
+            vec![U512::from(VER1_AMOUNT_1)],
+            vec![vec![hex::encode(leaf_1)]],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // AlreadySwapProceeded
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(9)))));
+}
+
+#[ignore]
+#[test]
+fn should_insert_snapshot_records_in_batch() {
+    // Genesis setting
+    let accounts = vec![GenesisAccount::new(
+        ADMIN_PUBKEY,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+    )];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot records in batch");
+    let batch_insert_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_records",
+            vec![VER1_ADDRESS, VER1_ADDRESS_2],
+            vec![U512::from(VER1_AMOUNT_1), U512::from(VER1_AMOUNT_2)],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(batch_insert_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    for (ver1_address, amount) in &[
+        (VER1_ADDRESS, VER1_AMOUNT_1),
+        (VER1_ADDRESS_2, VER1_AMOUNT_2),
+    ] {
+        let contract_ref = get_swap_stored_hash(&builder);
+        let value: BTreeMap<String, String> = CLValue::try_from(
+            builder
+                .query(
+                    Some(builder.get_post_state_hash()),
+                    contract_ref,
+                    &[*ver1_address],
+                )
+                .expect("cannot derive stored value"),
+        )
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully");
+
+        assert_eq!(value.get("prev_balance").unwrap(), &amount.to_string());
+        assert_eq!(value.get("is_swapped").unwrap(), "0");
+    }
+
+    println!("3. Insert a batch including an existing record. Should fail");
+    let batch_insert_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_records",
+            vec![VER1_ADDRESS_2],
+            vec![U512::from(VER1_AMOUNT_2)],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(batch_insert_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // DuplicatedSnapshotRecord
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(20)))));
+}
+
+#[ignore]
+#[test]
+fn should_amend_unclaimed_snapshot_record() {
+    // Genesis setting
+    let accounts = vec![GenesisAccount::new(
+        ADMIN_PUBKEY,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+    )];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Ver1 Token info insert");
+    let ver1_token_info_insert_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_record",
+            VER1_ADDRESS,
+            U512::from(VER1_AMOUNT_1),
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(ver1_token_info_insert_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_1)
+    );
+
+    println!("3. Insert the same address again. Should fail");
+    let ver1_token_info_insert_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_record",
+            VER1_ADDRESS,
+            U512::from(VER1_AMOUNT_1),
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(ver1_token_info_insert_request)
+        .commit()
+        .finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // DuplicatedSnapshotRecord
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(20)))));
+
+    println!("4. Amend the record. Purse is funded and refunded by the difference");
+    for amount in &[VER1_AMOUNT_1 + 5_000, VER1_AMOUNT_1 - 2_000] {
+        let amend_request = ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("amend_snapshot_record", VER1_ADDRESS, U512::from(*amount)),
+        )
+        .build();
+
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        builder.exec(amend_request).expect_success().commit();
+
+        let contract_ref = get_swap_stored_hash(&builder);
+        let value: BTreeMap<String, String> = CLValue::try_from(
+            builder
+                .query(
+                    Some(builder.get_post_state_hash()),
+                    contract_ref,
+                    &[VER1_ADDRESS],
+                )
+                .expect("cannot derive stored value"),
+        )
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully");
+
+        assert_eq!(value.get("prev_balance").unwrap(), &amount.to_string());
+        assert_eq!(
+            get_swap_contract_purse_balance(&builder),
+            U512::from(*amount)
+        );
+
+        result = builder.finish();
+    }
+}
+
+#[ignore]
+#[test]
+fn should_claim_with_signed_message() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot record, and register and upgrade KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token with the message signed for another account. Should fail");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let other_message = swap_message(&builder, ADMIN_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_signed_message",
+            contract_ref,
+            vec![other_message.clone()],
+            vec![sign_ver1_message(&other_message)],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // SwapMessageMismatch
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(25)))));
+
+    println!("4. Get token with the signed swap message");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let message = swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_signed_message",
+            contract_ref,
+            vec![message.clone()],
+            vec![sign_ver1_message(&message)],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    let value: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(value.get("is_swapped").unwrap(), "1");
+}
+
+#[ignore]
+#[test]
+fn should_claim_p2sh_address_with_multisig() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot record of the P2SH address, and register and upgrade KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_P2SH_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token with one of two required signatures. Should fail");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let message = swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_P2SH_ADDRESS);
+    let signature_1 = sign_with_ver1_key(&VER1_SECRET_KEY, &message);
+    let signature_2 = sign_with_ver1_key(&VER1_MULTISIG_SECRET_KEY, &message);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_multisig",
+            contract_ref,
+            vec![VER1_REDEEM_SCRIPT],
+            vec![vec![signature_1.clone()]],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // InvalidSignature
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(8)))));
+
+    println!("4. Get token with signatures ordered as the public keys");
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_multisig",
+            contract_ref,
+            vec![VER1_REDEEM_SCRIPT],
+            vec![vec![signature_1, signature_2]],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(get_token_request).expect_success().commit();
+
+    let value: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_P2SH_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(value.get("is_swapped").unwrap(), "1");
+}
+
+#[ignore]
+#[test]
+fn should_claim_with_uncompressed_pubkey() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot records of both addresses, and register and upgrade KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_records",
+                vec![VER1_ADDRESS, VER1_UNCOMPRESSED_ADDRESS],
+                vec![U512::from(VER1_AMOUNT_1), U512::from(VER1_AMOUNT_2)],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token with the uncompressed public key");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_UNCOMPRESSED_PUBKEY],
+            vec![sign_swap_message(
+                &builder,
+                ACCOUNT_1_PUBKEY,
+                VER1_UNCOMPRESSED_ADDRESS,
+            )],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    // Only the address of the uncompressed public key is claimed
+    for (ver1_address, is_swapped) in &[(VER1_UNCOMPRESSED_ADDRESS, "1"), (VER1_ADDRESS, "0")] {
+        let value: BTreeMap<String, String> = CLValue::try_from(
+            builder
+                .query(
+                    Some(builder.get_post_state_hash()),
+                    contract_ref,
+                    &[*ver1_address],
+                )
+                .expect("cannot derive stored value"),
+        )
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully");
+
+        assert_eq!(value.get("is_swapped").unwrap(), *is_swapped);
+    }
+}
+
+#[ignore]
+#[test]
+fn should_install_with_custom_network_params() {
+    // Private chain with its own version bytes and checksum XOR seed
+    const PUBKEY_HASH_PREFIX: u8 = 0x3c;
+    const SCRIPT_HASH_PREFIX: u8 = 0x05;
+    const CHECKSUM_XOR: u32 = 0x1234_5678;
+    // Address of VER1_PUBKEY in the private chain
+    const VER1_CUSTOM_ADDRESS: &str = "RQDAFMiWw5Wz3abi6sEibmTZKrGuymp53a";
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install with the network parameters");
+    let swap_install_request = ExecuteRequestBuilder::standard(
+        ADMIN_PUBKEY,
+        CONTRACT_POS_VOTE,
+        (PUBKEY_HASH_PREFIX, SCRIPT_HASH_PREFIX, CHECKSUM_XOR),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot record of the mainnet address. Should fail");
+    let insert_snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_record",
+            VER1_ADDRESS,
+            U512::from(VER1_AMOUNT_1),
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder.exec(insert_snapshot_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // MalformedVer1Address
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(21)))));
+
+    println!(
+        "3. Insert snapshot record of the private chain address, and register and upgrade KYC"
+    );
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_CUSTOM_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("4. Get token. The address is derived with the network parameters");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(
+                &builder,
+                ACCOUNT_1_PUBKEY,
+                VER1_CUSTOM_ADDRESS,
+            )],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    let value: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_CUSTOM_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(value.get("is_swapped").unwrap(), "1");
+}
+
+#[ignore]
+#[test]
+fn should_reject_snapshot_of_invalid_checksum() {
+    // VER1_ADDRESS with a typo in its checksum
+    const VER1_ADDRESS_TYPO: &str = "HMT6ZBkkjUFTguoycUaLuG1pjm83sA7ezN";
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot records including the typo. Should fail");
+    let insert_snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        (
+            "insert_snapshot_records",
+            vec![VER1_ADDRESS, VER1_ADDRESS_TYPO],
+            vec![U512::from(VER1_AMOUNT_1), U512::from(VER1_AMOUNT_2)],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(insert_snapshot_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // InvalidVer1AddressChecksum
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(28)))));
+
+    // Whole batch is reverted, including the valid record
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    assert!(builder
+        .query(
+            Some(builder.get_post_state_hash()),
+            contract_ref,
+            &[VER1_ADDRESS],
+        )
+        .is_err());
+}
+
+#[ignore]
+#[test]
+fn should_query_snapshot_kyc_and_configuration() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+    let contract_ref = get_swap_stored_hash(&builder);
+
+    println!("2. Insert allowance cap, snapshot record and KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Query existing records and configuration");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("get_snapshot_record", contract_ref, VER1_ADDRESS),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("get_kyc_record", contract_ref, ACCOUNT_1_PUBKEY),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("get_allowance_cap", contract_ref),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("get_admin", contract_ref),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("4. Query missing records, and a named key of the contract. Should fail");
+    let failing_requests = vec![
+        // SnapshotRecordNotFound
+        (
+            ExecuteRequestBuilder::contract_call_by_hash(
+                ACCOUNT_1_PUBKEY,
+                swap_contract_hash,
+                ("get_snapshot_record", contract_ref, VER1_ADDRESS_2),
+            )
+            .build(),
+            22,
+        ),
+        // MalformedVer1Address
+        (
+            ExecuteRequestBuilder::contract_call_by_hash(
+                ACCOUNT_1_PUBKEY,
+                swap_contract_hash,
+                ("get_snapshot_record", contract_ref, "admin"),
+            )
+            .build(),
+            21,
+        ),
+        // NotRegisteredKYC
+        (
+            ExecuteRequestBuilder::contract_call_by_hash(
+                ACCOUNT_1_PUBKEY,
+                swap_contract_hash,
+                ("get_kyc_record", contract_ref, ADMIN_PUBKEY),
+            )
+            .build(),
+            5,
+        ),
+    ];
+    for (request, error_code) in failing_requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).commit().finish();
+
+        let response = result
+            .builder()
+            .get_exec_response(0)
+            .expect("should have a response")
+            .to_owned();
+
+        let error_message = utils::get_error_message(response);
+        assert!(error_message.contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(error_code))
+        )));
+    }
+}
+
+#[ignore]
+#[test]
+fn should_refund_amendment_to_admin() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+    let swap_stored_hash = get_swap_stored_hash(&builder);
+
+    println!("2. Grant treasurer role, and insert snapshot");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("grant_role", "treasurer", ACCOUNT_1_PUBKEY),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("register_swap_hash", swap_stored_hash),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Treasurer decreases the balance. Refund goes to the admin");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let before_balance = builder.get_purse_balance(
+        builder
+            .get_account(ACCOUNT_1_PUBKEY)
+            .expect("should have account")
+            .main_purse(),
+    );
+
+    let amend_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "amend_snapshot_record",
+            VER1_ADDRESS,
+            U512::from(VER1_AMOUNT_1 - 2_000),
+        ),
+    )
+    .build();
+
+    builder.exec(amend_request).expect_success().commit();
+
+    let after_balance = builder.get_purse_balance(
+        builder
+            .get_account(ACCOUNT_1_PUBKEY)
+            .expect("should have account")
+            .main_purse(),
+    );
+
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_1 - 2_000)
+    );
+    assert_eq!(
+        // U512::from(BIGSUN_TO_HDAC / 10): Tx fee in test
+        (U512::from(BIGSUN_TO_HDAC / 10) + after_balance - before_balance) % U512::from(100_000),
+        U512::from(0),
+    );
+}
+
+#[ignore]
+#[test]
+fn should_transfer_admin_after_accept() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+    let swap_stored_hash = get_swap_stored_hash(&builder);
+
+    println!("2. Accept without proposal. Should fail");
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("accept_admin", swap_stored_hash),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder.exec(accept_admin_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NotPendingAdmin
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(29)))));
+
+    println!("3. Propose new admin. Admin is not changed yet");
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("propose_admin", ACCOUNT_1_PUBKEY),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder
+        .exec(propose_admin_request)
+        .expect_success()
+        .commit();
+    assert_eq!(get_admin(&builder), ADMIN_PUBKEY);
+    result = builder.finish();
+
+    println!("4. Accept by the new admin");
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("accept_admin", swap_stored_hash),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(accept_admin_request).expect_success().commit();
+    assert_eq!(get_admin(&builder), ACCOUNT_1_PUBKEY);
+    let result = builder.finish();
+
+    println!("5. Previous admin is not allowed anymore");
+    let allowance_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(allowance_cap_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NoPermission
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(30)))));
+
+    println!("6. New admin works through the proxy");
+    let allowance_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder
+        .exec(allowance_cap_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_check_role_of_operator() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+    let swap_stored_hash = get_swap_stored_hash(&builder);
+
+    println!("2. Grant KYC operator role, and register the contract to the operator account");
+    let grant_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("grant_role", "kyc_operator", ACCOUNT_1_PUBKEY),
+    )
+    .build();
+    let register_swap_hash_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("register_swap_hash", swap_stored_hash),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(grant_role_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(register_swap_hash_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    println!("3. Operator works within its role");
+    let allowance_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(allowance_cap_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    println!("4. Out of the role, unknown role, and revoked role. Should fail");
+    let requests = vec![
+        (
+            ExecuteRequestBuilder::contract_call_by_hash(
+                ACCOUNT_1_PUBKEY,
+                swap_contract_hash,
+                (
+                    "insert_snapshot_record",
+                    VER1_ADDRESS,
+                    U512::from(VER1_AMOUNT_1),
+                ),
+            )
+            .build(),
+            // NoPermission
+            ApiError::User(30),
+        ),
+        (
+            ExecuteRequestBuilder::contract_call_by_hash(
+                ADMIN_PUBKEY,
+                swap_contract_hash,
+                ("grant_role", "superuser", ACCOUNT_1_PUBKEY),
+            )
+            .build(),
+            // InvalidRole
+            ApiError::User(31),
+        ),
+    ];
+    for (request, expected_error) in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).commit().finish();
+
+        let response = result
+            .builder()
+            .get_exec_response(0)
+            .expect("should have a response")
+            .to_owned();
+
+        let error_message = utils::get_error_message(response);
+        assert!(error_message.contains(&format!("Revert({})", u32::from(expected_error))));
+    }
+
+    let revoke_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("revoke_role", "kyc_operator", ACCOUNT_1_PUBKEY),
+    )
+    .build();
+    let allowance_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(revoke_role_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(allowance_cap_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NoPermission
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(30)))));
+}
+
+#[ignore]
+#[test]
+fn should_execute_proposal_after_approvals() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+    let swap_stored_hash = get_swap_stored_hash(&builder);
+
+    println!("2. Configure 2-of-2 signers, and register the contract to the other signer");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "set_multisig_config",
+                vec![ADMIN_PUBKEY, ACCOUNT_1_PUBKEY],
+                U512::from(2),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("register_swap_hash", swap_stored_hash),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Direct call of a sensitive method. Should fail");
+    let allowance_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder.exec(allowance_cap_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // MultisigRequired
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(33)))));
+
+    println!("4. Propose and approve by one signer. Execution should fail");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "propose_action",
+                "insert_kyc_allowance_cap",
+                vec![SWAP_CAP_1.to_string()],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("approve_proposal", U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    let execute_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("execute_proposal", U512::from(1)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder.exec(execute_proposal_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NotEnoughApprovals
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(40)))));
+
+    println!("5. Approve and execute by the other signer");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("approve_proposal", U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            ("execute_proposal", U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let allowance_cap: U512 = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["kyc_border_allowance_cap"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+    assert_eq!(allowance_cap, U512::from(SWAP_CAP_1));
+
+    let pending_proposals: Vec<U512> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["multisig_pending_proposals"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+    assert!(pending_proposals.is_empty());
+
+    println!("6. Execute again. Should fail");
+    let execute_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("execute_proposal", U512::from(1)),
+    )
+    .build();
+
+    let result = builder.finish();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(execute_proposal_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // ProposalNotPending
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(38)))));
+
+    println!("7. Admin grants a role to itself without a proposal. Should fail");
+    let grant_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("grant_role", "treasurer", ADMIN_PUBKEY),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(grant_role_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // MultisigRequired
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(33)))));
+
+    println!("8. The other signer pauses without a proposal");
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("pause_swap",),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(pause_request).expect_success().commit();
+
+    let paused: bool = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["paused"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+    assert!(paused);
+
+    println!("9. Admin unpauses without a proposal. Should fail");
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("unpause_swap",),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(builder.finish());
+    let result = builder.exec(unpause_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // MultisigRequired
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(33)))));
+
+    println!("10. The other signer proposes to unpause, and admin cancels it. Should fail");
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("propose_action", "unpause_swap", Vec::<String>::new()),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(propose_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let cancel_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("cancel_proposal", U512::from(2)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(cancel_proposal_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NotProposer
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(65)))));
+
+    println!("11. The proposer cancels it");
+    let cancel_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("cancel_proposal", U512::from(2)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder
+        .exec(cancel_proposal_request)
+        .expect_success()
+        .commit();
+
+    let pending_proposals: Vec<U512> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["multisig_pending_proposals"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+    assert!(pending_proposals.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_refuse_claim_while_paused() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data, and pause");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("pause_swap",),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token while paused. Should fail");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // Paused
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(43)))));
+
+    println!("4. Unpause and get token");
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("unpause_swap",),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(unpause_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature],
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(get_token_request).expect_success().commit();
+
+    let value: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(value.get("is_swapped").unwrap(), "1");
+}
+
+#[ignore]
+#[test]
+fn should_refuse_claim_out_of_claim_window() {
+    const CLAIM_START: u64 = 1_000;
+    const CLAIM_END: u64 = 2_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data, and set claim window");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("set_claim_window", CLAIM_START, CLAIM_END),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    let builder = InMemoryWasmTestBuilder::from_result(result.clone());
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+
+    println!("3. Get token before and after the window. Should fail");
+    // (block time, expected error)
+    let failures = vec![
+        (CLAIM_START - 1, 44), // ClaimNotYetOpen
+        (CLAIM_END, 45),       // ClaimClosed
+    ];
+    for (block_time, error_code) in failures {
+        let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![signature.clone()],
+            ),
+        )
+        .with_block_time(block_time)
+        .build();
+
+        let mut builder = InMemoryWasmTestBuilder::from_result(result.clone());
+        let response = builder
+            .exec(get_token_request)
+            .commit()
+            .finish()
+            .builder()
+            .get_exec_response(0)
+            .expect("should have a response")
+            .to_owned();
+
+        let error_message = utils::get_error_message(response);
+        assert!(error_message.contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(error_code))
+        )));
+    }
+
+    println!("4. Get token in the window");
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature],
+        ),
+    )
+    .with_block_time(CLAIM_START)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(get_token_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_sweep_unclaimed_after_claim_end() {
+    const CLAIM_END: u64 = 1_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and set claim window");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("set_claim_window", 0u64, CLAIM_END),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Sweep before claim end. Should fail");
+    let sweep_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("sweep_unclaimed", Key::Account(ACCOUNT_1_PUBKEY)),
+    )
+    .with_block_time(CLAIM_END - 1)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(sweep_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // SweepNotAllowed
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(47)))));
+
+    println!("4. Sweep after claim end");
+    let sweep_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("sweep_unclaimed", Key::Account(ACCOUNT_1_PUBKEY)),
+    )
+    .with_block_time(CLAIM_END)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(sweep_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let treasury_balance = builder.get_purse_balance(
+        builder
+            .get_account(ACCOUNT_1_PUBKEY)
+            .expect("should have account")
+            .main_purse(),
+    );
+    assert_eq!(treasury_balance, U512::from(VER1_AMOUNT_1));
+    assert_eq!(get_swap_contract_purse_balance(&builder), U512::from(0));
+
+    println!("5. Sweep again. Should fail");
+    let sweep_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("sweep_unclaimed", Key::Account(ACCOUNT_1_PUBKEY)),
+    )
+    .with_block_time(CLAIM_END)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder.exec(sweep_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // AlreadySwept
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(48)))));
+}
+
+#[ignore]
+#[test]
+fn should_account_liabilities_and_claims() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot records and KYC data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_records",
+                vec![VER1_ADDRESS, VER1_ADDRESS_2],
+                vec![U512::from(VER1_AMOUNT_1), U512::from(VER1_AMOUNT_2)],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    assert_eq!(
+        get_stored_u512(&builder, "total_liabilities"),
+        U512::from(VER1_AMOUNT_1 + VER1_AMOUNT_2)
+    );
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_1 + VER1_AMOUNT_2)
+    );
+
+    println!("3. Get token");
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    // Outstanding liabilities of the other record are still covered
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(VER1_AMOUNT_1)
+    );
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_2)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_count_swap_stats() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot records, and register and upgrade KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_records",
+                vec![VER1_ADDRESS, VER1_ADDRESS_2],
+                vec![U512::from(VER1_AMOUNT_1), U512::from(VER1_AMOUNT_2)],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    let stats: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["swap_stats"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(stats.get("num_of_snapshot_records").unwrap(), "2");
+    assert_eq!(stats.get("num_of_claimed_records").unwrap(), "1");
+    assert_eq!(stats.get("num_of_claiming_accounts").unwrap(), "1");
+    assert_eq!(stats.get("kyc_level_1_registrations").unwrap(), "0");
+    assert_eq!(stats.get("kyc_level_2_registrations").unwrap(), "1");
+    assert_eq!(
+        stats.get("kyc_level_2_swapped_amount").unwrap(),
+        &VER1_AMOUNT_1.to_string()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_record_claim_receipt() {
+    const CLAIM_BLOCK_TIME: u64 = 1_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .with_block_time(CLAIM_BLOCK_TIME)
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    println!("4. Check receipt from both sides");
+    let receipt: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[&format!("receipt_{}", VER1_ADDRESS)],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(receipt.get("receipt_id").unwrap(), "1");
+    assert_eq!(receipt.get("ver1_address").unwrap(), VER1_ADDRESS);
+    assert_eq!(
+        receipt.get("mainnet_address").unwrap(),
+        &to_hex_string(ACCOUNT_1_PUBKEY)
+    );
+    assert_eq!(receipt.get("amount").unwrap(), &VER1_AMOUNT_1.to_string());
+    assert_eq!(
+        receipt.get("block_time").unwrap(),
+        &CLAIM_BLOCK_TIME.to_string()
+    );
+
+    let account_receipts: Vec<String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[&format!("receipts_{}", to_hex_string(ACCOUNT_1_PUBKEY))],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(account_receipts, vec![String::from(VER1_ADDRESS)]);
+}
+
+#[ignore]
+#[test]
+fn should_append_events_in_order() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Admin actions");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    println!("4. Check event log");
+    let expected_events = [
+        "CapChanged",
+        "SnapshotInserted",
+        "KycRegistered",
+        "KycLevelChanged",
+        "TokensClaimed",
+    ];
+    assert_eq!(
+        get_stored_u512(&builder, "event_count"),
+        U512::from(expected_events.len())
+    );
+
+    for (idx, event_type) in expected_events.iter().enumerate() {
+        let seq = idx + 1;
+        let event: BTreeMap<String, String> = CLValue::try_from(
+            builder
+                .query(
+                    Some(builder.get_post_state_hash()),
+                    contract_ref,
+                    &[&format!("event_{}", seq)],
+                )
+                .expect("cannot derive stored value"),
+        )
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully");
+
+        assert_eq!(event.get("seq").unwrap(), &seq.to_string());
+        assert_eq!(event.get("event_type").unwrap(), event_type);
+    }
+}
+
+#[ignore]
+#[test]
+fn should_release_partial_claim_after_kyc_upgrade() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot, cap and KYC level 1 data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token partially up to the cap");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+            true,
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    let snapshot_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(snapshot_record.get("is_swapped").unwrap(), "1");
+    assert_eq!(
+        snapshot_record.get("pending_amount").unwrap(),
+        &(VER1_AMOUNT_1 - SWAP_CAP_1).to_string()
+    );
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(SWAP_CAP_1)
+    );
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_1 - SWAP_CAP_1)
+    );
+
+    println!("4. Upgrade KYC level and release the remainder");
+    let update_kyc_level_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+    )
+    .build();
+
+    builder
+        .exec(update_kyc_level_request)
+        .expect_success()
+        .commit();
+
+    let snapshot_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(snapshot_record.get("pending_amount").unwrap(), "0");
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(VER1_AMOUNT_1)
+    );
+    assert_eq!(get_swap_contract_purse_balance(&builder), U512::from(0));
+}
+
+#[ignore]
+#[test]
+fn should_limit_claim_by_kyc_tier() {
+    const PERIOD_LIMIT: u64 = 3_000;
+    const PERIOD: u64 = 86_400_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. KYC data of a level out of the tier table");
+    let insert_kyc_data_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(3)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder.exec(insert_kyc_data_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // InvalidKYCLevelValue
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(7)))));
+
+    println!("3. Set tier of level 3 with a daily limit, and insert snapshot and KYC data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "set_kyc_tier",
+                U512::from(3),
+                U512::from(0),
+                U512::from(PERIOD_LIMIT),
+                PERIOD,
+                true,
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(3)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("4. Get token over the period limit");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // ExceededPeriodLimit
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(54)))));
+
+    println!("5. Get token partially up to the period limit");
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature],
+            true,
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(get_token_request).expect_success().commit();
+
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(PERIOD_LIMIT)
+    );
+
+    let kyc_tier: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &["kyc_tier_3"],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(kyc_tier.get("cap").unwrap(), "0");
+    assert_eq!(
+        kyc_tier.get("period_limit").unwrap(),
+        &PERIOD_LIMIT.to_string()
+    );
+    assert_eq!(kyc_tier.get("enabled").unwrap(), "true");
+}
+
+#[ignore]
+#[test]
+fn should_refuse_claim_after_kyc_expiry() {
+    const KYC_EXPIRES_AT: u64 = 1_000;
+    const KYC_RENEWED_EXPIRES_AT: u64 = 5_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data with expiry");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_kyc_data",
+                ACCOUNT_1_PUBKEY,
+                U512::from(2),
+                KYC_EXPIRES_AT,
+            ),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token after the expiry");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+        ),
+    )
+    .with_block_time(KYC_EXPIRES_AT)
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // KYCExpired
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(55)))));
+
+    println!("4. Renew KYC and get token");
+    let renew_kyc_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("renew_kyc", ACCOUNT_1_PUBKEY, KYC_RENEWED_EXPIRES_AT),
+    )
+    .with_block_time(KYC_EXPIRES_AT)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(renew_kyc_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature],
+        ),
+    )
+    .with_block_time(KYC_EXPIRES_AT)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(get_token_request).expect_success().commit();
+
+    let kyc_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[&to_hex_string(ACCOUNT_1_PUBKEY)],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(
+        kyc_record.get("expires_at").unwrap(),
+        &KYC_RENEWED_EXPIRES_AT.to_string()
+    );
+    assert_eq!(
+        kyc_record.get("swapped_amount").unwrap(),
+        &VER1_AMOUNT_1.to_string()
+    );
+    assert_eq!(
+        get_stored_u512(&builder, "kyc_account_count"),
+        U512::from(1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_freeze_and_revoke_kyc() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data, and freeze the account");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("freeze_account", ACCOUNT_1_PUBKEY),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token while frozen");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+        ),
+    )
+    .build();
+
+    let mut result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // AccountFrozen
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(57)))));
+
+    println!("4. Unfreeze, get token and revoke");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("unfreeze_account", ACCOUNT_1_PUBKEY),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![signature],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("revoke_kyc", ACCOUNT_1_PUBKEY),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    // Swapped amount is kept for auditing
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let kyc_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[&to_hex_string(ACCOUNT_1_PUBKEY)],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(kyc_record.get("status").unwrap(), "revoked");
+    assert_eq!(
+        kyc_record.get("swapped_amount").unwrap(),
+        &VER1_AMOUNT_1.to_string()
+    );
+
+    println!("5. Update KYC level of the revoked account");
+    let update_kyc_level_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+    )
+    .build();
+
+    let result = builder.exec(update_kyc_level_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // KYCRevoked
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(59)))));
+}
+
+#[ignore]
+#[test]
+fn should_refuse_claim_of_denylisted_address() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC data, and deny the ver1 address");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("add_to_denylist", vec![VER1_ADDRESS.to_string()]),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token of the denied address");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let signature = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![signature.clone()],
+        ),
+    )
+    .build();
+
+    let mut result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // DeniedVer1Address
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(60)))));
+
+    println!("4. Remove the address from the denylist by non-admin");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let remove_from_denylist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("remove_from_denylist", VER1_ADDRESS),
+    )
+    .build();
+
+    result = builder.exec(remove_from_denylist_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NotAdmin
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(1)))));
+
+    println!("5. Remove the address from the denylist by admin and get token");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("remove_from_denylist", VER1_ADDRESS),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![signature],
+            ),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(VER1_AMOUNT_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_expire_merkle_leaves_on_sweep() {
+    const CLAIM_END: u64 = 1_000;
+
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    // Both leaves are owned by the test key, as compressed and uncompressed public keys
+    let leaf_0 = merkle_leaf_hash(0, VER1_ADDRESS, U512::from(VER1_AMOUNT_1));
+    let leaf_1 = merkle_leaf_hash(1, VER1_UNCOMPRESSED_ADDRESS, U512::from(VER1_AMOUNT_2));
+    let merkle_root = merkle_node_hash(&leaf_0, &leaf_1);
+
+    println!("2. Insert snapshot merkle root, register and upgrade KYC, and set claim window");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_merkle_root",
+                hex::encode(merkle_root),
+                U512::from(VER1_AMOUNT_1 + VER1_AMOUNT_2),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("set_claim_window", 0u64, CLAIM_END),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token with the leaf 0, and sweep after claim end");
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token_with_merkle_proof",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+                vec![0u64],
+                vec![U512::from(VER1_AMOUNT_1)],
+                vec![vec![hex::encode(leaf_1)]],
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("sweep_unclaimed", Key::Account(ADMIN_PUBKEY)),
+        )
+        .with_block_time(CLAIM_END)
+        .build(),
+    ];
+    let signature_1 = sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_UNCOMPRESSED_ADDRESS);
+    let mut result = builder.finish();
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    // Balance of the unclaimed leaf is expired
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    assert_eq!(
+        get_stored_u512(&builder, "snapshot_merkle_claimed_amount"),
+        U512::from(VER1_AMOUNT_1)
+    );
+    assert_eq!(
+        get_stored_u512(&builder, "snapshot_merkle_expired"),
+        U512::from(VER1_AMOUNT_2)
+    );
+
+    println!("4. Get token with the leaf 1 after sweep. Should fail");
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token_with_merkle_proof",
+            contract_ref,
+            vec![VER1_UNCOMPRESSED_PUBKEY],
+            vec![signature_1],
+            vec![1u64],
+            vec![U512::from(VER1_AMOUNT_2)],
+            vec![vec![hex::encode(leaf_0)]],
+        ),
+    )
+    .with_block_time(CLAIM_END)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(builder.finish());
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // AlreadySwept
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(48)))));
+}
+
+#[ignore]
+#[test]
+fn should_claim_up_to_exact_cap() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert the cap equal to the snapshot balance, snapshot record and level 1 KYC");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(VER1_AMOUNT_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token of exactly the cap");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    builder.exec(get_token_request).expect_success().commit();
+
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(VER1_AMOUNT_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_claim_pending_remainder_after_unpause() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot, cap and KYC level 1 data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token partially, and upgrade KYC level while paused");
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+                true,
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("pause_swap",),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("unpause_swap",),
+        )
+        .build(),
+    ];
+    let mut result = builder.finish();
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    // The remainder is not released while paused
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(SWAP_CAP_1)
+    );
+
+    println!("4. Claim the pending remainder");
+    let claim_pending_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("claim_pending", contract_ref),
+    )
+    .build();
+
+    builder
+        .exec(claim_pending_request)
+        .expect_success()
+        .commit();
+
+    let snapshot_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(snapshot_record.get("pending_amount").unwrap(), "0");
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(VER1_AMOUNT_1)
+    );
+    assert_eq!(get_swap_contract_purse_balance(&builder), U512::from(0));
+
+    println!("5. Claim pending again. Should fail");
+    let claim_pending_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("claim_pending", contract_ref),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(builder.finish());
+    let result = builder.exec(claim_pending_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // NoPendingClaims
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(64)))));
+}
+
+#[ignore]
+#[test]
+fn should_refuse_level_1_claim_before_allowance_cap() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot and KYC level 1 data without the allowance cap");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Query the tier of level 1 without the allowance cap");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_kyc_tier_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("get_kyc_tier", contract_ref, U512::from(1)),
+    )
+    .build();
+
+    let result = builder
+        .exec(get_kyc_tier_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    println!("4. Get token. Should fail, as the cap is zero");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // ExceededSwapRange
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(2)))));
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_claim_address_without_snapshot_record() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert KYC data only. No snapshot record for the address");
+    let kyc_insert_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ADMIN_PUBKEY,
+        swap_contract_hash,
+        ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(2)),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let result = builder
+        .exec(kyc_insert_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    println!("3. Get token. Should fail, as the address has no record");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let get_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        (
+            "get_token",
+            contract_ref,
+            vec![VER1_PUBKEY],
+            vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+        ),
+    )
+    .build();
+
+    let result = builder.exec(get_token_request).commit().finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+
+    let error_message = utils::get_error_message(response);
+
+    // SnapshotRecordNotFound
+    assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::User(22)))));
+}
+
+#[ignore]
+#[test]
+fn should_keep_denylisted_remainder_pending() {
+    // Genesis setting
+    let accounts = vec![
+        GenesisAccount::new(
+            ADMIN_PUBKEY,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            ACCOUNT_1_PUBKEY,
+            Motes::new(U512::from(0)),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+    ];
+
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let result = builder.run_genesis(&genesis_config).commit().finish();
+
+    // Swap install phase
+    println!("1. Swap install");
+    let swap_install_request =
+        ExecuteRequestBuilder::standard(ADMIN_PUBKEY, CONTRACT_POS_VOTE, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let mut result = builder
+        .exec(swap_install_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let swap_contract_hash = get_swap_hash(&builder);
+
+    println!("2. Insert snapshot, cap and KYC level 1 data");
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            (
+                "insert_snapshot_record",
+                VER1_ADDRESS,
+                U512::from(VER1_AMOUNT_1),
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_allowance_cap", U512::from(SWAP_CAP_1)),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("insert_kyc_data", ACCOUNT_1_PUBKEY, U512::from(1)),
+        )
+        .build(),
+    ];
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("3. Get token partially, denylist the address and upgrade KYC level");
+    let builder = InMemoryWasmTestBuilder::from_result(result);
+    let contract_ref = get_swap_stored_hash(&builder);
+    let requests = vec![
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ACCOUNT_1_PUBKEY,
+            swap_contract_hash,
+            (
+                "get_token",
+                contract_ref,
+                vec![VER1_PUBKEY],
+                vec![sign_swap_message(&builder, ACCOUNT_1_PUBKEY, VER1_ADDRESS)],
+                true,
+            ),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("add_to_denylist", vec![VER1_ADDRESS.to_string()]),
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            ADMIN_PUBKEY,
+            swap_contract_hash,
+            ("update_kyc_level", ACCOUNT_1_PUBKEY, U512::from(2)),
+        )
+        .build(),
+    ];
+    let mut result = builder.finish();
+    for request in requests {
+        let mut builder = InMemoryWasmTestBuilder::from_result(result);
+        result = builder.exec(request).expect_success().commit().finish();
+    }
+
+    println!("4. Claim pending. The denylisted remainder stays pending");
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    let claim_pending_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_PUBKEY,
+        swap_contract_hash,
+        ("claim_pending", contract_ref),
+    )
+    .build();
+
+    builder
+        .exec(claim_pending_request)
+        .expect_success()
+        .commit();
+
+    let snapshot_record: BTreeMap<String, String> = CLValue::try_from(
+        builder
+            .query(
+                Some(builder.get_post_state_hash()),
+                contract_ref,
+                &[VER1_ADDRESS],
+            )
+            .expect("cannot derive stored value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+
+    assert_eq!(
+        snapshot_record.get("pending_amount").unwrap(),
+        &(VER1_AMOUNT_1 - SWAP_CAP_1).to_string()
+    );
+    assert_eq!(
+        get_stored_u512(&builder, "total_claimed"),
+        U512::from(SWAP_CAP_1)
+    );
+    assert_eq!(
+        get_swap_contract_purse_balance(&builder),
+        U512::from(VER1_AMOUNT_1 - SWAP_CAP_1)
+    );
+}